@@ -0,0 +1,221 @@
+// Generates the embedded ID tables from support/pci.ids. By default they
+// are sorted static arrays, looked up by binary search, so nothing is built
+// at startup. With the `ids-compressed` feature they're written as one
+// deflated blob instead, and without the `ids` feature nothing is embedded.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+const PCI_IDS: &str = "support/pci.ids";
+
+// IDs are packed into a u64, most significant field first, so the packed
+// keys sort the same way as the tuples
+struct Table {
+    name: &'static str,
+    key_type: &'static str,
+    // the width of each field of the key in hex digits
+    fields: &'static [usize],
+    entries: BTreeMap<u64, String>,
+}
+
+impl Table {
+    fn new(name: &'static str, key_type: &'static str, fields: &'static [usize]) -> Self {
+        Self {
+            name,
+            key_type,
+            fields,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    fn insert(&mut self, ids: &[u32], name: String) {
+        let key = ids
+            .iter()
+            .zip(self.fields)
+            .fold(0, |key, (id, digits)| key << (digits * 4) | *id as u64);
+        self.entries.insert(key, name);
+    }
+
+    // the key as a Rust literal, a tuple for keys of more than one field
+    fn format_key(&self, key: u64) -> String {
+        let mut shift = self.fields.iter().sum::<usize>() * 4;
+        let fields: Vec<String> = self
+            .fields
+            .iter()
+            .map(|digits| {
+                shift -= digits * 4;
+                let value = key >> shift & ((1 << (digits * 4)) - 1);
+                format!("0x{:0width$x}", value, width = digits)
+            })
+            .collect();
+
+        match fields.len() {
+            1 => fields[0].clone(),
+            _ => format!("({})", fields.join(", ")),
+        }
+    }
+
+    fn write_array(&self, out: &mut String) {
+        writeln!(
+            out,
+            "static {}: [({}, &str); {}] = [",
+            self.name,
+            self.key_type,
+            self.entries.len()
+        )
+        .unwrap();
+        for (key, value) in &self.entries {
+            writeln!(out, "    ({}, {:?}),", self.format_key(*key), value).unwrap();
+        }
+        writeln!(out, "];").unwrap();
+    }
+
+    // the number of entries, then a line per entry with the packed key in
+    // hex, a tab and the name
+    fn write_text(&self, out: &mut String) {
+        writeln!(out, "{}", self.entries.len()).unwrap();
+        for (key, value) in &self.entries {
+            writeln!(out, "{:x}\t{}", key, value).unwrap();
+        }
+    }
+}
+
+fn parse_hex(text: &str, digits: usize) -> Option<u32> {
+    if text.len() != digits {
+        return None;
+    }
+    u32::from_str_radix(text, 16).ok()
+}
+
+// the same format the library's runtime parser reads: vendors with their
+// devices and subsystems, then classes with subclasses and prog-ifs. The
+// tables come out in the order the library reads them back.
+fn parse(text: &str) -> [Table; 6] {
+    let mut vendors = Table::new("VENDORS", "u16", &[4]);
+    let mut devices = Table::new("DEVICES", "(u16, u16)", &[4, 4]);
+    let mut subsystems = Table::new("SUBSYSTEMS", "SubsystemKey", &[4, 4, 4, 4]);
+    let mut classes = Table::new("CLASSES", "u8", &[2]);
+    let mut subclasses = Table::new("SUBCLASSES", "(u8, u8)", &[2, 2]);
+    let mut prog_ifs = Table::new("PROG_IFS", "(u8, u8, u8)", &[2, 2, 2]);
+
+    let mut vendor = None;
+    let mut device = None;
+    let mut class = None;
+    let mut subclass = None;
+
+    for line in text.lines() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let depth = line.len() - line.trim_start_matches('\t').len();
+        let (id, name) = match line[depth..].split_once("  ") {
+            Some((id, name)) => (id, name.trim().to_string()),
+            None => continue,
+        };
+
+        match depth {
+            0 => {
+                vendor = parse_hex(id, 4);
+                class = id.strip_prefix("C ").and_then(|id| parse_hex(id, 2));
+                device = None;
+                subclass = None;
+                if let Some(vendor) = vendor {
+                    vendors.insert(&[vendor], name);
+                } else if let Some(class) = class {
+                    classes.insert(&[class], name);
+                }
+            }
+            // a line that isn't a device or subclass ends the previous one,
+            // so the lines below it aren't filed under it
+            1 => {
+                if let Some(vendor) = vendor {
+                    device = parse_hex(id, 4);
+                    if let Some(device) = device {
+                        devices.insert(&[vendor, device], name);
+                    }
+                } else if let Some(class) = class {
+                    subclass = parse_hex(id, 2);
+                    if let Some(subclass) = subclass {
+                        subclasses.insert(&[class, subclass], name);
+                    }
+                }
+            }
+            2 => {
+                if let (Some(vendor), Some(device)) = (vendor, device) {
+                    let ids = id.split_once(' ').and_then(|(subvendor, subdevice)| {
+                        Some((parse_hex(subvendor, 4)?, parse_hex(subdevice, 4)?))
+                    });
+                    if let Some((subvendor, subdevice)) = ids {
+                        subsystems.insert(&[vendor, device, subvendor, subdevice], name);
+                    }
+                } else if let (Some(class), Some(subclass), Some(id)) =
+                    (class, subclass, parse_hex(id, 2))
+                {
+                    prog_ifs.insert(&[class, subclass, id], name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    [vendors, devices, subsystems, classes, subclasses, prog_ifs]
+}
+
+// from the "Version: 2021.09.19" line of the header
+fn parse_version(text: &str) -> Option<&str> {
+    text.lines()
+        .take_while(|line| line.starts_with('#'))
+        .find_map(|line| line.trim_start_matches('#').trim().strip_prefix("Version:"))
+        .map(str::trim)
+}
+
+fn write_file(name: &str, content: &[u8]) {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join(name), content).unwrap();
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", PCI_IDS);
+
+    if env::var_os("CARGO_FEATURE_IDS").is_none() {
+        return;
+    }
+
+    let text = fs::read_to_string(PCI_IDS).unwrap();
+    let tables = parse(&text);
+
+    let version = parse_version(&text).unwrap_or("unknown");
+    write_file(
+        "version.rs",
+        format!("const VERSION: &str = {:?};\n", version).as_bytes(),
+    );
+
+    match env::var_os("CARGO_FEATURE_IDS_COMPRESSED") {
+        // the tables one after the other, each led by its length, checked
+        // here so the library can rely on it
+        Some(_) => {
+            let mut text = String::new();
+            for table in &tables {
+                table.write_text(&mut text);
+            }
+            let blob = miniz_oxide::deflate::compress_to_vec(text.as_bytes(), 10);
+            let inflated = miniz_oxide::inflate::decompress_to_vec(&blob).unwrap();
+            assert!(
+                inflated == text.as_bytes(),
+                "deflated ID tables don't round-trip"
+            );
+            write_file("tables.deflate", &blob);
+        }
+        None => {
+            let mut out = String::new();
+            for table in &tables {
+                table.write_array(&mut out);
+            }
+            write_file("tables.rs", out.as_bytes());
+        }
+    }
+}
@@ -1,5 +1,10 @@
+use core::fmt;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Address {
+    // PCI segment - 0 on most machines, Linux allows more than 16 bits
+    pub domain: u32,
+
     // 256 possible buses - fits in 1 byte
     pub bus: u8,
 
@@ -12,10 +17,29 @@ pub struct Address {
 
 impl Address {
     pub fn new(bus: u8, device: u8, function: u8) -> Self {
+        Self::with_domain(0, bus, device, function)
+    }
+
+    pub fn with_domain(domain: u32, bus: u8, device: u8, function: u8) -> Self {
         Self {
+            domain,
             bus,
             device,
             function,
         }
     }
 }
+
+// the domain is left out when it's 0, as lspci does
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.domain != 0 {
+            write!(f, "{:04x}:", self.domain)?;
+        }
+        write!(
+            f,
+            "{:02x}:{:02x}.{:x}",
+            self.bus, self.device, self.function
+        )
+    }
+}
@@ -0,0 +1,283 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::header_type::{
+    HeaderTypeRegister, HEADER_LAYOUT_BRIDGE, HEADER_LAYOUT_GENERAL,
+};
+use crate::config_space::resource::ResourceSizes;
+use crate::config_space::ConfigSpace;
+
+const BAR_OFFSET: usize = 0x10;
+const HEADER_TYPE_OFFSET: usize = 0x0e;
+const ROM_OFFSET_GENERAL: usize = 0x30;
+const ROM_OFFSET_BRIDGE: usize = 0x38;
+const ROM_INDEX: usize = 6;
+
+const SIZE_UNITS: [&str; 5] = ["", "K", "M", "G", "T"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarKind {
+    Io,
+    Memory32,
+    // memory type 01b, reserved since PCI 3.0
+    MemoryBelow1M,
+    Memory64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaseAddressRegister {
+    // index of the (lower) register, 0-5
+    pub index: usize,
+    pub kind: BarKind,
+    pub is_prefetchable: bool,
+    pub address: u64,
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpansionRom {
+    pub address: u64,
+    pub is_enabled: bool,
+    pub size: Option<u64>,
+}
+
+pub struct BarDecoder {}
+
+impl Default for BarDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BarDecoder {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn bar_count(&self, cf: &ConfigSpace) -> usize {
+        let header_type = HeaderTypeRegister::from(cf.byte(HEADER_TYPE_OFFSET));
+        match header_type.layout() {
+            HEADER_LAYOUT_GENERAL => 6,
+            HEADER_LAYOUT_BRIDGE => 2,
+            _ => 0,
+        }
+    }
+
+    /// Decodes the BARs of a device, merging each 64-bit pair into a single
+    /// entry. Registers that are zero and have no known size are skipped.
+    pub fn decode(&self, cf: &ConfigSpace, sizes: &ResourceSizes) -> Vec<BaseAddressRegister> {
+        let count = self.bar_count(cf);
+        let registers: Vec<u32> = (0..count)
+            .map(|index| cf.dword(BAR_OFFSET + index * 4))
+            .collect();
+        let sizes: Vec<Option<u64>> = (0..count).map(|index| sizes.get(index)).collect();
+
+        self.decode_registers(&registers, &sizes)
+    }
+
+    /// Decodes a run of raw BAR dwords, such as the VF BARs of an SR-IOV
+    /// capability. `sizes` is indexed like `registers` and may be shorter.
+    pub fn decode_registers(
+        &self,
+        registers: &[u32],
+        sizes: &[Option<u64>],
+    ) -> Vec<BaseAddressRegister> {
+        let count = registers.len();
+        let mut bars = vec![];
+        let mut index = 0;
+
+        while index < count {
+            let value = registers[index];
+            let size = sizes.get(index).copied().flatten();
+
+            let (kind, is_prefetchable, address, width) = match value & 0x1 {
+                1 => (BarKind::Io, false, (value & !0x3) as u64, 1),
+                _ => {
+                    let is_prefetchable = value & 0x8 > 0;
+                    let lower = (value & !0xf) as u64;
+                    match (value >> 1) & 0x3 {
+                        0x2 => {
+                            // a 64-bit BAR in the last slot has no upper half
+                            let upper = match index + 1 < count {
+                                true => registers[index + 1] as u64,
+                                false => 0,
+                            };
+                            (BarKind::Memory64, is_prefetchable, upper << 32 | lower, 2)
+                        }
+                        0x1 => (BarKind::MemoryBelow1M, is_prefetchable, lower, 1),
+                        _ => (BarKind::Memory32, is_prefetchable, lower, 1),
+                    }
+                }
+            };
+
+            if value != 0 || size.is_some() {
+                bars.push(BaseAddressRegister {
+                    index,
+                    kind,
+                    is_prefetchable,
+                    address,
+                    size,
+                });
+            }
+
+            index += width;
+        }
+
+        bars
+    }
+
+    pub fn decode_rom(&self, cf: &ConfigSpace, sizes: &ResourceSizes) -> Option<ExpansionRom> {
+        let header_type = HeaderTypeRegister::from(cf.byte(HEADER_TYPE_OFFSET));
+        let offset = match header_type.layout() {
+            HEADER_LAYOUT_GENERAL => ROM_OFFSET_GENERAL,
+            HEADER_LAYOUT_BRIDGE => ROM_OFFSET_BRIDGE,
+            _ => return None,
+        };
+
+        let value = cf.dword(offset);
+        let size = sizes.get(ROM_INDEX);
+        if value == 0 && size.is_none() {
+            return None;
+        }
+
+        Some(ExpansionRom {
+            address: (value & 0xffff_f800) as u64,
+            is_enabled: value & 0x1 > 0,
+            size,
+        })
+    }
+}
+
+pub fn format_size(size: u64) -> String {
+    let mut value = size;
+    let mut unit = 0;
+
+    while value >= 1024 && value % 1024 == 0 && unit < SIZE_UNITS.len() - 1 {
+        value /= 1024;
+        unit += 1;
+    }
+
+    format!("{}{}", value, SIZE_UNITS[unit])
+}
+
+pub struct BarPrettyPrinter {}
+
+impl Default for BarPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BarPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, bar: &BaseAddressRegister) -> String {
+        let kind = match bar.kind {
+            BarKind::Io => format!("I/O ports at 0x{:04x}", bar.address),
+            _ => {
+                let width = match bar.kind {
+                    BarKind::Memory64 => "64-bit",
+                    BarKind::MemoryBelow1M => "32-bit (below 1M)",
+                    _ => "32-bit",
+                };
+                let prefetch = match bar.is_prefetchable {
+                    true => "prefetchable",
+                    false => "non-prefetchable",
+                };
+                format!("{} {} memory at 0x{:x}", width, prefetch, bar.address)
+            }
+        };
+
+        match bar.size {
+            Some(size) => format!("{} [size={}]", kind, format_size(size)),
+            None => kind,
+        }
+    }
+
+    pub fn print_rom(&self, rom: &ExpansionRom) -> String {
+        let mut line = format!("expansion ROM at 0x{:08x}", rom.address);
+        if !rom.is_enabled {
+            line.push_str(" [disabled]");
+        }
+        if let Some(size) = rom.size {
+            line.push_str(&format!(" [size={}]", format_size(size)));
+        }
+        line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a general device with a 64-bit prefetchable BAR in 0-1 and an I/O BAR
+    // in 2
+    fn config_space() -> ConfigSpace {
+        let mut bytes = vec![0; 0x40];
+        let registers: [u32; 3] = [0xe000_000c, 0x0000_0004, 0x0000_e001];
+        for (index, value) in registers.iter().enumerate() {
+            let offset = BAR_OFFSET + index * 4;
+            bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+        ConfigSpace::from(bytes)
+    }
+
+    fn sizes() -> ResourceSizes {
+        ResourceSizes::from_sysfs_resource(concat!(
+            "0x00000004e0000000 0x00000004efffffff 0x000000000014220c\n",
+            "0x0000000000000000 0x0000000000000000 0x0000000000000000\n",
+            "0x000000000000e000 0x000000000000e0ff 0x0000000000040101\n",
+        ))
+    }
+
+    #[test]
+    fn merges_a_64_bit_pair() {
+        let bars = BarDecoder::new().decode(&config_space(), &sizes());
+        assert_eq!(bars.len(), 2);
+        assert_eq!(
+            bars[0],
+            BaseAddressRegister {
+                index: 0,
+                kind: BarKind::Memory64,
+                is_prefetchable: true,
+                address: 0x4_e000_0000,
+                size: Some(256 << 20),
+            }
+        );
+        assert_eq!(
+            BarPrettyPrinter::new().print(&bars[0]),
+            "64-bit prefetchable memory at 0x4e0000000 [size=256M]"
+        );
+    }
+
+    #[test]
+    fn decodes_an_io_bar() {
+        let bars = BarDecoder::new().decode(&config_space(), &sizes());
+        assert_eq!(bars[1].index, 2);
+        assert_eq!(bars[1].kind, BarKind::Io);
+        assert_eq!(bars[1].address, 0xe000);
+        assert_eq!(
+            BarPrettyPrinter::new().print(&bars[1]),
+            "I/O ports at 0xe000 [size=256]"
+        );
+    }
+
+    #[test]
+    fn keeps_a_64_bit_bar_in_the_last_slot() {
+        let bars = BarDecoder::new().decode_registers(&[0, 0xfe00_0004], &[]);
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].index, 1);
+        assert_eq!(bars[0].address, 0xfe00_0000);
+    }
+
+    #[test]
+    fn formats_sizes() {
+        assert_eq!(format_size(256), "256");
+        assert_eq!(format_size(16 << 10), "16K");
+        assert_eq!(format_size(256 << 20), "256M");
+        assert_eq!(format_size(8 << 30), "8G");
+        assert_eq!(format_size(1536), "1536");
+    }
+}
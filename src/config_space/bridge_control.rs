@@ -0,0 +1,202 @@
+use alloc::string::String;
+
+use crate::config_space::shared::format_bitvec_flags;
+
+use super::shared::BitVecFieldDescriptor;
+
+const BRIDGE_CONTROL_FIELDS: [BitVecFieldDescriptor; 13] = [
+    // Parity Error Response Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ParErr",
+        is_reserved: false,
+    },
+    // SERR# Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "SERR",
+        is_reserved: false,
+    },
+    // ISA Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ISA",
+        is_reserved: false,
+    },
+    // VGA Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "VGA",
+        is_reserved: false,
+    },
+    // VGA 16-bit Decode
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "VGA16",
+        is_reserved: false,
+    },
+    // Master Abort Mode
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MAbort",
+        is_reserved: false,
+    },
+    // Secondary Bus Reset
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "BusReset",
+        is_reserved: false,
+    },
+    // Fast Back-to-Back Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "FastB2B",
+        is_reserved: false,
+    },
+    // Primary Discard Timer
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PriDiscTmr",
+        is_reserved: false,
+    },
+    // Secondary Discard Timer
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "SecDiscTmr",
+        is_reserved: false,
+    },
+    // Discard Timer Status
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "DiscTmrStat",
+        is_reserved: false,
+    },
+    // Discard Timer SERR# Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "DiscTmrSERREn",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 4,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+const CARDBUS_BRIDGE_CONTROL_FIELDS: [BitVecFieldDescriptor; 12] = [
+    // Parity Error Response Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ParErr",
+        is_reserved: false,
+    },
+    // SERR# Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "SERR",
+        is_reserved: false,
+    },
+    // ISA Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ISA",
+        is_reserved: false,
+    },
+    // VGA Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "VGA",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Master Abort Mode
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MAbort",
+        is_reserved: false,
+    },
+    // CardBus Reset
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CardBusReset",
+        is_reserved: false,
+    },
+    // 16-bit PC Card IREQ/INT Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "16BitInt",
+        is_reserved: false,
+    },
+    // Memory Window 0 Prefetch Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PrefMem0",
+        is_reserved: false,
+    },
+    // Memory Window 1 Prefetch Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PrefMem1",
+        is_reserved: false,
+    },
+    // Write Posting Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PostWrites",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 5,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+];
+
+pub struct BridgeControlRegister {
+    vector: u16,
+}
+
+impl From<u16> for BridgeControlRegister {
+    fn from(value: u16) -> Self {
+        Self { vector: value }
+    }
+}
+
+pub struct BridgeControlPrettyPrinter {}
+
+impl BridgeControlPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, reg: &BridgeControlRegister) -> String {
+        format_bitvec_flags(reg.vector as u32, &BRIDGE_CONTROL_FIELDS)
+    }
+}
+
+pub struct CardBusBridgeControlRegister {
+    vector: u16,
+}
+
+impl From<u16> for CardBusBridgeControlRegister {
+    fn from(value: u16) -> Self {
+        Self { vector: value }
+    }
+}
+
+pub struct CardBusBridgeControlPrettyPrinter {}
+
+impl CardBusBridgeControlPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, reg: &CardBusBridgeControlRegister) -> String {
+        format_bitvec_flags(reg.vector as u32, &CARDBUS_BRIDGE_CONTROL_FIELDS)
+    }
+}
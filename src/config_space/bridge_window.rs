@@ -0,0 +1,88 @@
+use crate::config_space::ConfigSpace;
+
+// offsets into the Type 1 header
+const IO_BASE: usize = 0x1c;
+const IO_LIMIT: usize = 0x1d;
+const MEMORY_BASE: usize = 0x20;
+const MEMORY_LIMIT: usize = 0x22;
+const PREFETCH_BASE: usize = 0x24;
+const PREFETCH_LIMIT: usize = 0x26;
+const PREFETCH_BASE_UPPER: usize = 0x28;
+const PREFETCH_LIMIT_UPPER: usize = 0x2c;
+const IO_BASE_UPPER: usize = 0x30;
+const IO_LIMIT_UPPER: usize = 0x32;
+
+/// An address range that a bridge forwards from its primary to its secondary
+/// bus. The window is closed when `base` is above `limit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BridgeWindow {
+    pub base: u64,
+    pub limit: u64,
+    // 32-bit I/O decoding or 64-bit prefetchable memory decoding
+    pub is_wide: bool,
+}
+
+impl BridgeWindow {
+    pub fn is_enabled(&self) -> bool {
+        self.base <= self.limit
+    }
+
+    pub fn size(&self) -> Option<u64> {
+        match self.is_enabled() {
+            true => Some(self.limit - self.base + 1),
+            false => None,
+        }
+    }
+}
+
+/// The I/O, memory and prefetchable memory windows of a PCI-to-PCI bridge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BridgeWindows {
+    pub io: BridgeWindow,
+    pub memory: BridgeWindow,
+    pub prefetchable: BridgeWindow,
+}
+
+impl From<&ConfigSpace> for BridgeWindows {
+    fn from(cf: &ConfigSpace) -> Self {
+        // I/O: bits 7:4 hold address bits 15:12, bits 3:0 the addressing capability
+        let io_base = cf.byte(IO_BASE);
+        let io_limit = cf.byte(IO_LIMIT);
+        let io_is_wide = io_base & 0x0f == 0x01;
+        let mut io = BridgeWindow {
+            base: ((io_base & 0xf0) as u64) << 8,
+            limit: ((io_limit & 0xf0) as u64) << 8 | 0xfff,
+            is_wide: io_is_wide,
+        };
+        if io_is_wide {
+            io.base |= (cf.word(IO_BASE_UPPER) as u64) << 16;
+            io.limit |= (cf.word(IO_LIMIT_UPPER) as u64) << 16;
+        }
+
+        // memory: bits 15:4 hold address bits 31:20
+        let memory = BridgeWindow {
+            base: ((cf.word(MEMORY_BASE) & 0xfff0) as u64) << 16,
+            limit: ((cf.word(MEMORY_LIMIT) & 0xfff0) as u64) << 16 | 0xfffff,
+            is_wide: false,
+        };
+
+        let pref_base = cf.word(PREFETCH_BASE);
+        let pref_limit = cf.word(PREFETCH_LIMIT);
+        let pref_is_wide = pref_base & 0x000f == 0x0001;
+        let mut prefetchable = BridgeWindow {
+            base: ((pref_base & 0xfff0) as u64) << 16,
+            limit: ((pref_limit & 0xfff0) as u64) << 16 | 0xfffff,
+            is_wide: pref_is_wide,
+        };
+        if pref_is_wide {
+            prefetchable.base |= (cf.dword(PREFETCH_BASE_UPPER) as u64) << 32;
+            prefetchable.limit |= (cf.dword(PREFETCH_LIMIT_UPPER) as u64) << 32;
+        }
+
+        Self {
+            io,
+            memory,
+            prefetchable,
+        }
+    }
+}
@@ -0,0 +1,285 @@
+mod msi;
+mod pcie;
+mod power;
+mod vpd;
+
+use alloc::vec;
+use alloc::{string::String, vec::Vec};
+
+use crate::config_space::header_type::{HeaderTypeRegister, HEADER_LAYOUT_CARDBUS};
+use crate::config_space::status::StatusRegister;
+use crate::config_space::vendor_specific::{
+    VendorCapability, VendorCapabilityPrettyPrinter, VendorCapabilityRegistry,
+};
+use crate::config_space::ConfigSpace;
+
+const STATUS_OFFSET: usize = 0x06;
+const HEADER_TYPE_OFFSET: usize = 0x0e;
+const CAPABILITIES_PTR_OFFSET: usize = 0x34;
+const CARDBUS_CAPABILITIES_PTR_OFFSET: usize = 0x14;
+
+// capabilities live after the predefined header
+const CAPABILITIES_START: usize = 0x40;
+
+pub use msi::{MsiCapability, MsiPrettyPrinter, MsixCapability};
+pub use pcie::{
+    aspm_name, link_speed_name, PciExpressCapability, PciExpressPrettyPrinter, PortType,
+};
+pub use power::{PowerManagementCapability, PowerManagementPrettyPrinter, PowerState};
+pub use vpd::{VpdCapability, VpdCapabilityPrettyPrinter};
+
+pub const CAP_ID_POWER_MANAGEMENT: u8 = 0x01;
+pub const CAP_ID_VPD: u8 = 0x03;
+pub const CAP_ID_MSI: u8 = 0x05;
+pub const CAP_ID_VENDOR_SPECIFIC: u8 = 0x09;
+pub const CAP_ID_PCI_EXPRESS: u8 = 0x10;
+pub const CAP_ID_MSIX: u8 = 0x11;
+
+const CAPABILITY_NAMES: [(u8, &str); 21] = [
+    (0x01, "Power Management"),
+    (0x02, "AGP"),
+    (0x03, "Vital Product Data"),
+    (0x04, "Slot Identification"),
+    (0x05, "MSI"),
+    (0x06, "CompactPCI Hot Swap"),
+    (0x07, "PCI-X"),
+    (0x08, "HyperTransport"),
+    (0x09, "Vendor Specific"),
+    (0x0a, "Debug Port"),
+    (0x0b, "CompactPCI Central Resource Control"),
+    (0x0c, "PCI Hot-Plug"),
+    (0x0d, "Bridge Subsystem Vendor ID"),
+    (0x0e, "AGP 8x"),
+    (0x0f, "Secure Device"),
+    (0x10, "PCI Express"),
+    (0x11, "MSI-X"),
+    (0x12, "SATA Data/Index Configuration"),
+    (0x13, "Advanced Features"),
+    (0x14, "Enhanced Allocation"),
+    (0x15, "Flattening Portal Bridge"),
+];
+
+pub fn capability_name(id: u8) -> Option<&'static str> {
+    CAPABILITY_NAMES
+        .iter()
+        .find(|(cap_id, _)| *cap_id == id)
+        .map(|(_, name)| *name)
+}
+
+/// An entry in the standard capability list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capability {
+    pub offset: u8,
+    pub id: u8,
+    pub next: u8,
+}
+
+/// Reasons the capability list can't be followed any further. Each carries
+/// the offending pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapabilityError {
+    // the pointer leads back to a capability already visited
+    Loop(u8),
+    // the pointer isn't dword aligned
+    Misaligned(u8),
+    // the pointer is inside the header or past the readable config space
+    OutOfBounds(u8),
+}
+
+/// Walks the standard capability list. After an error is yielded the
+/// iterator is exhausted.
+pub struct CapabilityIter<'a> {
+    cf: &'a ConfigSpace,
+    next: u8,
+    // one bit per dword in 0x00-0xff
+    visited: u64,
+}
+
+impl<'a> CapabilityIter<'a> {
+    pub fn new(cf: &'a ConfigSpace) -> Self {
+        let status = StatusRegister::from(cf.word(STATUS_OFFSET));
+        let header_type = HeaderTypeRegister::from(cf.byte(HEADER_TYPE_OFFSET));
+
+        let next = match (status.has_capabilities(), header_type.layout()) {
+            (false, _) => 0,
+            (true, HEADER_LAYOUT_CARDBUS) => cf.byte(CARDBUS_CAPABILITIES_PTR_OFFSET),
+            (true, _) => cf.byte(CAPABILITIES_PTR_OFFSET),
+        };
+
+        Self {
+            cf,
+            next,
+            visited: 0,
+        }
+    }
+
+    fn step(&mut self, offset: u8) -> Result<Capability, CapabilityError> {
+        if offset & 0x3 != 0 {
+            return Err(CapabilityError::Misaligned(offset));
+        }
+
+        let position = offset as usize;
+        if position < CAPABILITIES_START || position + 2 > self.cf.len() {
+            return Err(CapabilityError::OutOfBounds(offset));
+        }
+
+        let bit = 1u64 << (position / 4);
+        if self.visited & bit > 0 {
+            return Err(CapabilityError::Loop(offset));
+        }
+        self.visited |= bit;
+
+        Ok(Capability {
+            offset,
+            id: self.cf.byte(position),
+            next: self.cf.byte(position + 1),
+        })
+    }
+}
+
+impl<'a> Iterator for CapabilityIter<'a> {
+    type Item = Result<Capability, CapabilityError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == 0 {
+            return None;
+        }
+
+        let result = self.step(self.next);
+        self.next = match result {
+            Ok(cap) => cap.next,
+            Err(_) => 0,
+        };
+
+        Some(result)
+    }
+}
+
+/// Decodes the body of the capabilities it knows about. Others produce no
+/// lines, leaving just the name.
+pub struct CapabilityPrettyPrinter {}
+
+impl Default for CapabilityPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CapabilityPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(
+        &self,
+        cf: &ConfigSpace,
+        cap: &Capability,
+        vendor_decoders: &VendorCapabilityRegistry,
+    ) -> Vec<String> {
+        match cap.id {
+            CAP_ID_VENDOR_SPECIFIC => {
+                let vendor = VendorCapability::read_vendor(cf, cap.offset);
+                VendorCapabilityPrettyPrinter::new().print(vendor, vendor_decoders)
+            }
+            CAP_ID_PCI_EXPRESS => match PciExpressCapability::read(cf, cap.offset) {
+                Some(pcie) => PciExpressPrettyPrinter::new().print(&pcie),
+                None => vec![String::from("[truncated]")],
+            },
+            CAP_ID_POWER_MANAGEMENT => match PowerManagementCapability::read(cf, cap.offset) {
+                Some(pm) => PowerManagementPrettyPrinter::new().print(&pm),
+                None => vec![String::from("[truncated]")],
+            },
+            CAP_ID_VPD => match VpdCapability::read(cf, cap.offset) {
+                Some(vpd) => VpdCapabilityPrettyPrinter::new().print(&vpd),
+                None => vec![String::from("[truncated]")],
+            },
+            CAP_ID_MSI => match MsiCapability::read(cf, cap.offset) {
+                Some(msi) => MsiPrettyPrinter::new().print(&msi),
+                None => vec![String::from("[truncated]")],
+            },
+            CAP_ID_MSIX => match MsixCapability::read(cf, cap.offset) {
+                Some(msix) => MsiPrettyPrinter::new().print_msix(&msix),
+                None => vec![String::from("[truncated]")],
+            },
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a type 0 header with a capability list of (offset, id, next) entries
+    fn config_space(len: usize, first: u8, caps: &[(u8, u8, u8)]) -> ConfigSpace {
+        let mut bytes = vec![0; len];
+        bytes[STATUS_OFFSET] = 0x10;
+        bytes[CAPABILITIES_PTR_OFFSET] = first;
+        for (offset, id, next) in caps {
+            bytes[*offset as usize] = *id;
+            bytes[*offset as usize + 1] = *next;
+        }
+        ConfigSpace::from(bytes)
+    }
+
+    fn walk(cf: &ConfigSpace) -> Vec<Result<u8, CapabilityError>> {
+        cf.capabilities()
+            .map(|result| result.map(|cap| cap.offset))
+            .collect()
+    }
+
+    #[test]
+    fn walks_the_list() {
+        let cf = config_space(256, 0x40, &[(0x40, 0x01, 0x50), (0x50, 0x10, 0x00)]);
+        assert_eq!(walk(&cf), vec![Ok(0x40), Ok(0x50)]);
+
+        let caps: Vec<Capability> = cf.capabilities().filter_map(Result::ok).collect();
+        assert_eq!(
+            caps[1],
+            Capability {
+                offset: 0x50,
+                id: 0x10,
+                next: 0x00
+            }
+        );
+    }
+
+    #[test]
+    fn yields_nothing_without_the_status_bit() {
+        let mut bytes = vec![0; 256];
+        bytes[CAPABILITIES_PTR_OFFSET] = 0x40;
+        bytes[0x40] = 0x10;
+        assert_eq!(walk(&ConfigSpace::from(bytes)), vec![]);
+    }
+
+    #[test]
+    fn stops_at_a_loop() {
+        let cf = config_space(256, 0x40, &[(0x40, 0x01, 0x50), (0x50, 0x05, 0x40)]);
+        assert_eq!(
+            walk(&cf),
+            vec![Ok(0x40), Ok(0x50), Err(CapabilityError::Loop(0x40))]
+        );
+    }
+
+    #[test]
+    fn stops_at_a_misaligned_pointer() {
+        let cf = config_space(256, 0x40, &[(0x40, 0x01, 0x51)]);
+        assert_eq!(
+            walk(&cf),
+            vec![Ok(0x40), Err(CapabilityError::Misaligned(0x51))]
+        );
+    }
+
+    #[test]
+    fn stops_at_a_pointer_out_of_bounds() {
+        let cf = config_space(256, 0x40, &[(0x40, 0x01, 0x20)]);
+        assert_eq!(
+            walk(&cf),
+            vec![Ok(0x40), Err(CapabilityError::OutOfBounds(0x20))]
+        );
+
+        // only the header is readable without root
+        let cf = config_space(64, 0x40, &[]);
+        assert_eq!(walk(&cf), vec![Err(CapabilityError::OutOfBounds(0x40))]);
+    }
+}
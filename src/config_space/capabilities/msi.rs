@@ -0,0 +1,284 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::shared::{
+    format_bitvec_flags, push_register_lines, BitVecFieldDescriptor,
+};
+use crate::config_space::ConfigSpace;
+
+// register offsets relative to the start of the capability
+const MSI_CONTROL: usize = 0x02;
+const MSI_ADDRESS: usize = 0x04;
+const MSI_ADDRESS_UPPER: usize = 0x08;
+const MSIX_CONTROL: usize = 0x02;
+const MSIX_TABLE: usize = 0x04;
+const MSIX_PBA: usize = 0x08;
+
+const MSIX_CAPABILITY_LEN: usize = 0x0c;
+
+const MSI_CONTROL_FIELDS: [BitVecFieldDescriptor; 6] = [
+    // MSI Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Enable",
+        is_reserved: false,
+    },
+    // Multiple Message Capable, Multiple Message Enable
+    BitVecFieldDescriptor {
+        len: 6,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // 64 bit address capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "64bit",
+        is_reserved: false,
+    },
+    // Per-Vector Masking Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Maskable",
+        is_reserved: false,
+    },
+    // Extended Message Data Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ExtMsgData",
+        is_reserved: false,
+    },
+    // Extended Message Data Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ExtMsgDataEn",
+        is_reserved: false,
+    },
+];
+
+const MSIX_CONTROL_FIELDS: [BitVecFieldDescriptor; 4] = [
+    // Table Size
+    BitVecFieldDescriptor {
+        len: 11,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    BitVecFieldDescriptor {
+        len: 3,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // Function Mask
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "FuncMask",
+        is_reserved: false,
+    },
+    // MSI-X Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Enable",
+        is_reserved: false,
+    },
+];
+
+/// The MSI capability (ID 0x05). The position of the data, mask and pending
+/// registers depends on whether the function is 64-bit capable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MsiCapability {
+    pub offset: u8,
+    pub control: u16,
+    pub address: u64,
+    pub data: u16,
+    pub mask: Option<u32>,
+    pub pending: Option<u32>,
+}
+
+impl MsiCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u8) -> Option<Self> {
+        let base = offset as usize;
+        if base + MSI_CONTROL + 2 > cf.len() {
+            return None;
+        }
+
+        let control = cf.word(base + MSI_CONTROL);
+        let is_64bit = control & 0x0080 > 0;
+        let is_maskable = control & 0x0100 > 0;
+
+        let data_offset = match is_64bit {
+            true => 0x0c,
+            false => 0x08,
+        };
+        let len = match is_maskable {
+            true => data_offset + 0x0c,
+            false => data_offset + 0x02,
+        };
+        if base + len > cf.len() {
+            return None;
+        }
+
+        let mut address = cf.dword(base + MSI_ADDRESS) as u64;
+        if is_64bit {
+            address |= (cf.dword(base + MSI_ADDRESS_UPPER) as u64) << 32;
+        }
+
+        let (mask, pending) = match is_maskable {
+            true => (
+                Some(cf.dword(base + data_offset + 0x04)),
+                Some(cf.dword(base + data_offset + 0x08)),
+            ),
+            false => (None, None),
+        };
+
+        Some(Self {
+            offset,
+            control,
+            address,
+            data: cf.word(base + data_offset),
+            mask,
+            pending,
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.control & 0x0001 > 0
+    }
+
+    pub fn is_64bit(&self) -> bool {
+        self.control & 0x0080 > 0
+    }
+
+    pub fn is_maskable(&self) -> bool {
+        self.control & 0x0100 > 0
+    }
+
+    // both counts are encoded as log2 of the number of vectors
+    pub fn messages_capable(&self) -> u32 {
+        1 << ((self.control >> 1) & 0x7)
+    }
+
+    pub fn messages_enabled(&self) -> u32 {
+        1 << ((self.control >> 4) & 0x7)
+    }
+}
+
+/// The MSI-X capability (ID 0x11). The vector table and pending bit array
+/// live in memory behind one of the BARs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MsixCapability {
+    pub offset: u8,
+    pub control: u16,
+    pub table: u32,
+    pub pba: u32,
+}
+
+impl MsixCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u8) -> Option<Self> {
+        let base = offset as usize;
+        if base + MSIX_CAPABILITY_LEN > cf.len() {
+            return None;
+        }
+
+        Some(Self {
+            offset,
+            control: cf.word(base + MSIX_CONTROL),
+            table: cf.dword(base + MSIX_TABLE),
+            pba: cf.dword(base + MSIX_PBA),
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.control & 0x8000 > 0
+    }
+
+    pub fn is_function_masked(&self) -> bool {
+        self.control & 0x4000 > 0
+    }
+
+    // encoded as N-1
+    pub fn table_size(&self) -> u32 {
+        (self.control & 0x07ff) as u32 + 1
+    }
+
+    pub fn table_bar(&self) -> u8 {
+        (self.table & 0x7) as u8
+    }
+
+    pub fn table_offset(&self) -> u32 {
+        self.table & !0x7
+    }
+
+    pub fn pba_bar(&self) -> u8 {
+        (self.pba & 0x7) as u8
+    }
+
+    pub fn pba_offset(&self) -> u32 {
+        self.pba & !0x7
+    }
+}
+
+pub struct MsiPrettyPrinter {}
+
+impl Default for MsiPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MsiPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, cap: &MsiCapability) -> Vec<String> {
+        let mut lines = vec![];
+
+        let values = format!(
+            "Count {}/{}",
+            cap.messages_enabled(),
+            cap.messages_capable()
+        );
+        let flags = format_bitvec_flags(cap.control as u32, &MSI_CONTROL_FIELDS);
+        push_register_lines("MsgCtl", values, flags, &mut lines);
+
+        let address = match cap.is_64bit() {
+            true => format!("0x{:016x}", cap.address),
+            false => format!("0x{:08x}", cap.address),
+        };
+        lines.push(format!("{:<9}: {}", "Address", address));
+        lines.push(format!("{:<9}: 0x{:04x}", "Data", cap.data));
+
+        if let (Some(mask), Some(pending)) = (cap.mask, cap.pending) {
+            lines.push(format!("{:<9}: 0x{:08x}", "Masking", mask));
+            lines.push(format!("{:<9}: 0x{:08x}", "Pending", pending));
+        }
+
+        lines
+    }
+
+    pub fn print_msix(&self, cap: &MsixCapability) -> Vec<String> {
+        let mut lines = vec![];
+
+        let values = format!("Count {}", cap.table_size());
+        let flags = format_bitvec_flags(cap.control as u32, &MSIX_CONTROL_FIELDS);
+        push_register_lines("MsgCtl", values, flags, &mut lines);
+
+        lines.push(format!(
+            "{:<9}: BAR {}, offset 0x{:08x}",
+            "Table",
+            cap.table_bar(),
+            cap.table_offset()
+        ));
+        lines.push(format!(
+            "{:<9}: BAR {}, offset 0x{:08x}",
+            "PBA",
+            cap.pba_bar(),
+            cap.pba_offset()
+        ));
+
+        lines
+    }
+}
@@ -0,0 +1,1600 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::shared::{
+    format_bitvec_flags, push_register_lines, BitVecFieldDescriptor,
+};
+use crate::config_space::ConfigSpace;
+
+// register offsets relative to the start of the capability
+const CAPABILITIES: usize = 0x02;
+const DEVICE_CAPABILITIES: usize = 0x04;
+const DEVICE_CONTROL: usize = 0x08;
+const DEVICE_STATUS: usize = 0x0a;
+const LINK_CAPABILITIES: usize = 0x0c;
+const LINK_CONTROL: usize = 0x10;
+const LINK_STATUS: usize = 0x12;
+const SLOT_CAPABILITIES: usize = 0x14;
+const SLOT_CONTROL: usize = 0x18;
+const SLOT_STATUS: usize = 0x1a;
+const ROOT_CONTROL: usize = 0x1c;
+const ROOT_CAPABILITIES: usize = 0x1e;
+const ROOT_STATUS: usize = 0x20;
+const DEVICE_CAPABILITIES_2: usize = 0x24;
+const DEVICE_CONTROL_2: usize = 0x28;
+const DEVICE_STATUS_2: usize = 0x2a;
+const LINK_CAPABILITIES_2: usize = 0x2c;
+const LINK_CONTROL_2: usize = 0x30;
+const LINK_STATUS_2: usize = 0x32;
+const SLOT_CAPABILITIES_2: usize = 0x34;
+const SLOT_CONTROL_2: usize = 0x38;
+const SLOT_STATUS_2: usize = 0x3a;
+
+// version 1 capabilities end after the root registers
+const CAPABILITY_V1_LEN: usize = 0x24;
+const CAPABILITY_V2_LEN: usize = 0x3c;
+
+const L0S_ACCEPTABLE_LATENCIES: [&str; 8] = [
+    "<64ns",
+    "<128ns",
+    "<256ns",
+    "<512ns",
+    "<1us",
+    "<2us",
+    "<4us",
+    "unlimited",
+];
+const L1_ACCEPTABLE_LATENCIES: [&str; 8] = [
+    "<1us",
+    "<2us",
+    "<4us",
+    "<8us",
+    "<16us",
+    "<32us",
+    "<64us",
+    "unlimited",
+];
+const L0S_EXIT_LATENCIES: [&str; 8] = [
+    "<64ns", "<128ns", "<256ns", "<512ns", "<1us", "<2us", "<4us", ">4us",
+];
+const L1_EXIT_LATENCIES: [&str; 8] = [
+    "<1us", "<2us", "<4us", "<8us", "<16us", "<32us", "<64us", ">64us",
+];
+const INDICATOR_STATES: [&str; 4] = ["Unknown", "On", "Blink", "Off"];
+
+const DEVICE_CAPABILITIES_FIELDS: [BitVecFieldDescriptor; 8] = [
+    // MaxPayload, PhantFunc
+    BitVecFieldDescriptor {
+        len: 5,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Extended Tag Field Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ExtTag",
+        is_reserved: false,
+    },
+    // L0s/L1 Acceptable Latency, undefined
+    BitVecFieldDescriptor {
+        len: 9,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // Role-Based Error Reporting
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "RBE",
+        is_reserved: false,
+    },
+    // ERR_COR Subclass Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ErrCorSub",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Reserved 3",
+        is_reserved: true,
+    },
+    // Captured Slot Power Limit Value/Scale
+    BitVecFieldDescriptor {
+        len: 10,
+        name: "Reserved 4",
+        is_reserved: true,
+    },
+    // Function Level Reset Capability
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "FLReset",
+        is_reserved: false,
+    },
+];
+
+const DEVICE_CONTROL_FIELDS: [BitVecFieldDescriptor; 12] = [
+    // Correctable Error Reporting Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CorrErr",
+        is_reserved: false,
+    },
+    // Non-Fatal Error Reporting Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "NonFatalErr",
+        is_reserved: false,
+    },
+    // Fatal Error Reporting Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "FatalErr",
+        is_reserved: false,
+    },
+    // Unsupported Request Reporting Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "UnsupReq",
+        is_reserved: false,
+    },
+    // Enable Relaxed Ordering
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "RlxdOrd",
+        is_reserved: false,
+    },
+    // Max_Payload_Size
+    BitVecFieldDescriptor {
+        len: 3,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Extended Tag Field Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ExtTag",
+        is_reserved: false,
+    },
+    // Phantom Functions Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PhantFunc",
+        is_reserved: false,
+    },
+    // Aux Power PM Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "AuxPwr",
+        is_reserved: false,
+    },
+    // Enable No Snoop
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "NoSnoop",
+        is_reserved: false,
+    },
+    // Max_Read_Request_Size
+    BitVecFieldDescriptor {
+        len: 3,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // Bridge Configuration Retry Enable / Initiate Function Level Reset
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "FLReset",
+        is_reserved: false,
+    },
+];
+
+const DEVICE_STATUS_FIELDS: [BitVecFieldDescriptor; 8] = [
+    // Correctable Error Detected
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CorrErr",
+        is_reserved: false,
+    },
+    // Non-Fatal Error Detected
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "NonFatalErr",
+        is_reserved: false,
+    },
+    // Fatal Error Detected
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "FatalErr",
+        is_reserved: false,
+    },
+    // Unsupported Request Detected
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "UnsupReq",
+        is_reserved: false,
+    },
+    // AUX Power Detected
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "AuxPwr",
+        is_reserved: false,
+    },
+    // Transactions Pending
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "TransPend",
+        is_reserved: false,
+    },
+    // Emergency Power Reduction Detected
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "EmergencyPowerReduction",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 9,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+const LINK_CAPABILITIES_FIELDS: [BitVecFieldDescriptor; 7] = [
+    // Speed, Width, ASPM, exit latencies
+    BitVecFieldDescriptor {
+        len: 18,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Clock Power Management
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ClockPM",
+        is_reserved: false,
+    },
+    // Surprise Down Error Reporting Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Surprise",
+        is_reserved: false,
+    },
+    // Data Link Layer Link Active Reporting Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "LLActRep",
+        is_reserved: false,
+    },
+    // Link Bandwidth Notification Capability
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "BwNot",
+        is_reserved: false,
+    },
+    // ASPM Optionality Compliance
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ASPMOptComp",
+        is_reserved: false,
+    },
+    // Port Number
+    BitVecFieldDescriptor {
+        len: 9,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+];
+
+const LINK_CONTROL_FIELDS: [BitVecFieldDescriptor; 11] = [
+    // ASPM Control
+    BitVecFieldDescriptor {
+        len: 3,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Read Completion Boundary (128 bytes when set)
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "RCB",
+        is_reserved: false,
+    },
+    // Link Disable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "LnkDisable",
+        is_reserved: false,
+    },
+    // Retrain Link
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Retrain",
+        is_reserved: false,
+    },
+    // Common Clock Configuration
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CommClk",
+        is_reserved: false,
+    },
+    // Extended Synch
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ExtSynch",
+        is_reserved: false,
+    },
+    // Enable Clock Power Management
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ClockPM",
+        is_reserved: false,
+    },
+    // Hardware Autonomous Width Disable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "AutWidDis",
+        is_reserved: false,
+    },
+    // Link Bandwidth Management Interrupt Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "BWInt",
+        is_reserved: false,
+    },
+    // Link Autonomous Bandwidth Interrupt Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "AutBWInt",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 4,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+];
+
+const LINK_STATUS_FIELDS: [BitVecFieldDescriptor; 6] = [
+    // Current Link Speed, Negotiated Link Width
+    BitVecFieldDescriptor {
+        len: 11,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Link Training
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Training",
+        is_reserved: false,
+    },
+    // Slot Clock Configuration
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "SlotClk",
+        is_reserved: false,
+    },
+    // Data Link Layer Link Active
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "DLActive",
+        is_reserved: false,
+    },
+    // Link Bandwidth Management Status
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "BWMgmt",
+        is_reserved: false,
+    },
+    // Link Autonomous Bandwidth Status
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ABWMgmt",
+        is_reserved: false,
+    },
+];
+
+const SLOT_CAPABILITIES_FIELDS: [BitVecFieldDescriptor; 11] = [
+    // Attention Button Present
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "AttnBtn",
+        is_reserved: false,
+    },
+    // Power Controller Present
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PwrCtrl",
+        is_reserved: false,
+    },
+    // MRL Sensor Present
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MRL",
+        is_reserved: false,
+    },
+    // Attention Indicator Present
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "AttnInd",
+        is_reserved: false,
+    },
+    // Power Indicator Present
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PwrInd",
+        is_reserved: false,
+    },
+    // Hot-Plug Surprise
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Surprise",
+        is_reserved: false,
+    },
+    // Hot-Plug Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "HotPlug",
+        is_reserved: false,
+    },
+    // Slot Power Limit Value/Scale
+    BitVecFieldDescriptor {
+        len: 10,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Electromechanical Interlock Present
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Interlock",
+        is_reserved: false,
+    },
+    // No Command Completed Support
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "NoCompl",
+        is_reserved: false,
+    },
+    // Physical Slot Number
+    BitVecFieldDescriptor {
+        len: 13,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+];
+
+const SLOT_CONTROL_FIELDS: [BitVecFieldDescriptor; 11] = [
+    // Attention Button Pressed Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "AttnBtn",
+        is_reserved: false,
+    },
+    // Power Fault Detected Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PwrFlt",
+        is_reserved: false,
+    },
+    // MRL Sensor Changed Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MRL",
+        is_reserved: false,
+    },
+    // Presence Detect Changed Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PresDet",
+        is_reserved: false,
+    },
+    // Command Completed Interrupt Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CmdCplt",
+        is_reserved: false,
+    },
+    // Hot-Plug Interrupt Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "HPIrq",
+        is_reserved: false,
+    },
+    // Attention/Power Indicator Control
+    BitVecFieldDescriptor {
+        len: 4,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Power Controller Control
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PwrOff",
+        is_reserved: false,
+    },
+    // Electromechanical Interlock Control
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Interlock",
+        is_reserved: false,
+    },
+    // Data Link Layer State Changed Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "LinkChg",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 3,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+];
+
+const SLOT_STATUS_FIELDS: [BitVecFieldDescriptor; 10] = [
+    // Attention Button Pressed
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "AttnBtn",
+        is_reserved: false,
+    },
+    // Power Fault Detected
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PowerFlt",
+        is_reserved: false,
+    },
+    // MRL Sensor Changed
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MRL",
+        is_reserved: false,
+    },
+    // Command Completed
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CmdCplt",
+        is_reserved: false,
+    },
+    // Presence Detect Changed
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PresDet",
+        is_reserved: false,
+    },
+    // MRL Sensor State
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MRLOpen",
+        is_reserved: false,
+    },
+    // Presence Detect State
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Presence",
+        is_reserved: false,
+    },
+    // Electromechanical Interlock Status
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Interlock",
+        is_reserved: false,
+    },
+    // Data Link Layer State Changed
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "LinkChg",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 7,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+const ROOT_CONTROL_FIELDS: [BitVecFieldDescriptor; 6] = [
+    // System Error on Correctable Error Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ErrCorrectable",
+        is_reserved: false,
+    },
+    // System Error on Non-Fatal Error Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ErrNon-Fatal",
+        is_reserved: false,
+    },
+    // System Error on Fatal Error Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ErrFatal",
+        is_reserved: false,
+    },
+    // PME Interrupt Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PMEIntEna",
+        is_reserved: false,
+    },
+    // CRS Software Visibility Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CRSVisible",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 11,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+const ROOT_STATUS_FIELDS: [BitVecFieldDescriptor; 4] = [
+    // PME Requester ID
+    BitVecFieldDescriptor {
+        len: 16,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // PME Status
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PMEStatus",
+        is_reserved: false,
+    },
+    // PME Pending
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PMEPending",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 14,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+];
+
+const DEVICE_CAPABILITIES_2_FIELDS: [BitVecFieldDescriptor; 17] = [
+    // Completion Timeout Ranges Supported
+    BitVecFieldDescriptor {
+        len: 4,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Completion Timeout Disable Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "TimeoutDis",
+        is_reserved: false,
+    },
+    // ARI Forwarding Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ARIFwd",
+        is_reserved: false,
+    },
+    // AtomicOp Routing Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "AtomicOpsRouting",
+        is_reserved: false,
+    },
+    // 32-bit AtomicOp Completer Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "32bitAtomicComp",
+        is_reserved: false,
+    },
+    // 64-bit AtomicOp Completer Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "64bitAtomicComp",
+        is_reserved: false,
+    },
+    // 128-bit CAS Completer Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "128bitCASComp",
+        is_reserved: false,
+    },
+    // No RO-enabled PR-PR Passing
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "NROPrPrP",
+        is_reserved: false,
+    },
+    // LTR Mechanism Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "LTR",
+        is_reserved: false,
+    },
+    // TPH Completer Supported, LN System CLS
+    BitVecFieldDescriptor {
+        len: 4,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // 10-Bit Tag Completer Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "10BitTagComp",
+        is_reserved: false,
+    },
+    // 10-Bit Tag Requester Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "10BitTagReq",
+        is_reserved: false,
+    },
+    // OBFF Supported
+    BitVecFieldDescriptor {
+        len: 2,
+        name: "Reserved 3",
+        is_reserved: true,
+    },
+    // Extended Fmt Field Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ExtFmt",
+        is_reserved: false,
+    },
+    // End-End TLP Prefix Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "EETLPPrefix",
+        is_reserved: false,
+    },
+    // Max End-End TLP Prefixes, Emergency Power Reduction
+    BitVecFieldDescriptor {
+        len: 9,
+        name: "Reserved 4",
+        is_reserved: true,
+    },
+    // FRS Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "FRS",
+        is_reserved: false,
+    },
+];
+
+const DEVICE_CONTROL_2_FIELDS: [BitVecFieldDescriptor; 12] = [
+    // Completion Timeout Value
+    BitVecFieldDescriptor {
+        len: 4,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Completion Timeout Disable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "TimeoutDis",
+        is_reserved: false,
+    },
+    // ARI Forwarding Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ARIFwd",
+        is_reserved: false,
+    },
+    // AtomicOp Requester Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "AtomicOpsReq",
+        is_reserved: false,
+    },
+    // AtomicOp Egress Blocking
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "AtomicOpsEgressBlck",
+        is_reserved: false,
+    },
+    // IDO Request Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "IDOReq",
+        is_reserved: false,
+    },
+    // IDO Completion Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "IDOCompl",
+        is_reserved: false,
+    },
+    // LTR Mechanism Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "LTR",
+        is_reserved: false,
+    },
+    // Emergency Power Reduction Request
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "EmergencyPowerReduction",
+        is_reserved: false,
+    },
+    // 10-Bit Tag Requester Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "10BitTagReq",
+        is_reserved: false,
+    },
+    // OBFF Enable
+    BitVecFieldDescriptor {
+        len: 2,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // End-End TLP Prefix Blocking
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "EETLPPrefixBlk",
+        is_reserved: false,
+    },
+];
+
+const LINK_CAPABILITIES_2_FIELDS: [BitVecFieldDescriptor; 7] = [
+    // Supported Link Speeds Vector
+    BitVecFieldDescriptor {
+        len: 8,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Crosslink Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Crosslink",
+        is_reserved: false,
+    },
+    // Lower SKP OS Generation/Reception Supported Speeds
+    BitVecFieldDescriptor {
+        len: 14,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // Retimer Presence Detect Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Retimer",
+        is_reserved: false,
+    },
+    // Two Retimers Presence Detect Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "2Retimers",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 6,
+        name: "Reserved 3",
+        is_reserved: true,
+    },
+    // DRS Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "DRS",
+        is_reserved: false,
+    },
+];
+
+const LINK_CONTROL_2_FIELDS: [BitVecFieldDescriptor; 8] = [
+    // Target Link Speed
+    BitVecFieldDescriptor {
+        len: 4,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Enter Compliance
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "EnterCompliance",
+        is_reserved: false,
+    },
+    // Hardware Autonomous Speed Disable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "SpeedDis",
+        is_reserved: false,
+    },
+    // Selectable De-emphasis
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "SelectableDeemphasis",
+        is_reserved: false,
+    },
+    // Transmit Margin
+    BitVecFieldDescriptor {
+        len: 3,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // Enter Modified Compliance
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "EnterModifiedCompliance",
+        is_reserved: false,
+    },
+    // Compliance SOS
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ComplianceSOS",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 4,
+        name: "Reserved 3",
+        is_reserved: true,
+    },
+];
+
+const LINK_STATUS_2_FIELDS: [BitVecFieldDescriptor; 10] = [
+    // Current De-emphasis Level
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Equalization Complete
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "EqualizationComplete",
+        is_reserved: false,
+    },
+    // Equalization Phase 1 Successful
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "EqualizationPhase1",
+        is_reserved: false,
+    },
+    // Equalization Phase 2 Successful
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "EqualizationPhase2",
+        is_reserved: false,
+    },
+    // Equalization Phase 3 Successful
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "EqualizationPhase3",
+        is_reserved: false,
+    },
+    // Link Equalization Request
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "LinkEqualizationRequest",
+        is_reserved: false,
+    },
+    // Retimer Presence Detected
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Retimer",
+        is_reserved: false,
+    },
+    // Two Retimers Presence Detected
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "2Retimers",
+        is_reserved: false,
+    },
+    // Crosslink Resolution, Downstream Component Presence
+    BitVecFieldDescriptor {
+        len: 7,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // DRS Message Received
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "DRSRcvd",
+        is_reserved: false,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortType {
+    Endpoint,
+    LegacyEndpoint,
+    RootPort,
+    UpstreamPort,
+    DownstreamPort,
+    PcieToPciBridge,
+    PciToPcieBridge,
+    RootComplexEndpoint,
+    RootComplexEventCollector,
+    Unknown(u8),
+}
+
+impl From<u8> for PortType {
+    fn from(value: u8) -> Self {
+        match value {
+            0x0 => PortType::Endpoint,
+            0x1 => PortType::LegacyEndpoint,
+            0x4 => PortType::RootPort,
+            0x5 => PortType::UpstreamPort,
+            0x6 => PortType::DownstreamPort,
+            0x7 => PortType::PcieToPciBridge,
+            0x8 => PortType::PciToPcieBridge,
+            0x9 => PortType::RootComplexEndpoint,
+            0xa => PortType::RootComplexEventCollector,
+            _ => PortType::Unknown(value),
+        }
+    }
+}
+
+impl PortType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PortType::Endpoint => "Endpoint",
+            PortType::LegacyEndpoint => "Legacy Endpoint",
+            PortType::RootPort => "Root Port",
+            PortType::UpstreamPort => "Upstream Port",
+            PortType::DownstreamPort => "Downstream Port",
+            PortType::PcieToPciBridge => "PCI-Express to PCI/PCI-X Bridge",
+            PortType::PciToPcieBridge => "PCI/PCI-X to PCI-Express Bridge",
+            PortType::RootComplexEndpoint => "Root Complex Integrated Endpoint",
+            PortType::RootComplexEventCollector => "Root Complex Event Collector",
+            PortType::Unknown(_) => "Unknown",
+        }
+    }
+
+    // the port at the upstream end of a link, facing a device below it
+    pub fn is_downstream_facing(&self) -> bool {
+        matches!(
+            self,
+            PortType::RootPort | PortType::DownstreamPort | PortType::PciToPcieBridge
+        )
+    }
+}
+
+/// Link speed in GT/s as encoded in LnkCap, LnkSta and LnkCtl2.
+pub fn link_speed_name(value: u8) -> &'static str {
+    match value {
+        1 => "2.5GT/s",
+        2 => "5GT/s",
+        3 => "8GT/s",
+        4 => "16GT/s",
+        5 => "32GT/s",
+        6 => "64GT/s",
+        _ => "unknown",
+    }
+}
+
+/// ASPM states as encoded in LnkCap (support) and LnkCtl (control).
+pub fn aspm_name(value: u8) -> &'static str {
+    match value & 0x3 {
+        0 => "None",
+        1 => "L0s",
+        2 => "L1",
+        _ => "L0s L1",
+    }
+}
+
+fn format_power_limit(value: u32, scale: u32) -> String {
+    let milliwatts = value * [1000, 100, 10, 1][scale as usize & 0x3];
+    match milliwatts % 1000 {
+        0 => format!("{}W", milliwatts / 1000),
+        fraction => {
+            let fraction = format!("{:03}", fraction);
+            format!("{}.{}W", milliwatts / 1000, fraction.trim_end_matches('0'))
+        }
+    }
+}
+
+fn completion_timeout_ranges(value: u32) -> String {
+    match value & 0xf {
+        0 => String::from("Not Supported"),
+        ranges => {
+            let letters: String = ['A', 'B', 'C', 'D']
+                .iter()
+                .enumerate()
+                .filter(|(bit, _)| ranges & (1 << bit) > 0)
+                .map(|(_, letter)| *letter)
+                .collect();
+            format!("Range {}", letters)
+        }
+    }
+}
+
+fn completion_timeout_value(value: u16) -> &'static str {
+    match value & 0xf {
+        0x0 => "50us to 50ms",
+        0x1 => "50us to 100us",
+        0x2 => "1ms to 10ms",
+        0x5 => "16ms to 55ms",
+        0x6 => "65ms to 210ms",
+        0x9 => "260ms to 900ms",
+        0xa => "1s to 3.5s",
+        0xd => "4s to 13s",
+        0xe => "17s to 64s",
+        _ => "reserved",
+    }
+}
+
+fn supported_link_speeds(vector: u32) -> String {
+    let speeds: Vec<&str> = (1..=7)
+        .filter(|bit| vector & (1 << bit) > 0)
+        .map(|bit| link_speed_name(bit as u8))
+        .collect();
+    match speeds.is_empty() {
+        true => String::from("none"),
+        false => speeds.join(", "),
+    }
+}
+
+/// The PCI Express capability (ID 0x10). Registers the port type doesn't
+/// implement read as zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PciExpressCapability {
+    pub offset: u8,
+    pub capabilities: u16,
+    pub device_capabilities: u32,
+    pub device_control: u16,
+    pub device_status: u16,
+    pub link_capabilities: u32,
+    pub link_control: u16,
+    pub link_status: u16,
+    pub slot_capabilities: u32,
+    pub slot_control: u16,
+    pub slot_status: u16,
+    pub root_control: u16,
+    pub root_capabilities: u16,
+    pub root_status: u32,
+    pub device_capabilities_2: u32,
+    pub device_control_2: u16,
+    pub device_status_2: u16,
+    pub link_capabilities_2: u32,
+    pub link_control_2: u16,
+    pub link_status_2: u16,
+    pub slot_capabilities_2: u32,
+    pub slot_control_2: u16,
+    pub slot_status_2: u16,
+    /// Whether the version 2 registers were read, which they aren't if
+    /// they run past the end of the readable config space.
+    pub has_version_2_registers: bool,
+}
+
+impl PciExpressCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u8) -> Option<Self> {
+        let base = offset as usize;
+        if base + CAPABILITY_V1_LEN > cf.len() {
+            return None;
+        }
+
+        let mut cap = Self {
+            offset,
+            capabilities: cf.word(base + CAPABILITIES),
+            device_capabilities: cf.dword(base + DEVICE_CAPABILITIES),
+            device_control: cf.word(base + DEVICE_CONTROL),
+            device_status: cf.word(base + DEVICE_STATUS),
+            link_capabilities: 0,
+            link_control: 0,
+            link_status: 0,
+            slot_capabilities: 0,
+            slot_control: 0,
+            slot_status: 0,
+            root_control: 0,
+            root_capabilities: 0,
+            root_status: 0,
+            device_capabilities_2: 0,
+            device_control_2: 0,
+            device_status_2: 0,
+            link_capabilities_2: 0,
+            link_control_2: 0,
+            link_status_2: 0,
+            slot_capabilities_2: 0,
+            slot_control_2: 0,
+            slot_status_2: 0,
+            has_version_2_registers: false,
+        };
+
+        if cap.has_link() {
+            cap.link_capabilities = cf.dword(base + LINK_CAPABILITIES);
+            cap.link_control = cf.word(base + LINK_CONTROL);
+            cap.link_status = cf.word(base + LINK_STATUS);
+        }
+        if cap.has_slot() {
+            cap.slot_capabilities = cf.dword(base + SLOT_CAPABILITIES);
+            cap.slot_control = cf.word(base + SLOT_CONTROL);
+            cap.slot_status = cf.word(base + SLOT_STATUS);
+        }
+        if cap.has_root() {
+            cap.root_control = cf.word(base + ROOT_CONTROL);
+            cap.root_capabilities = cf.word(base + ROOT_CAPABILITIES);
+            cap.root_status = cf.dword(base + ROOT_STATUS);
+        }
+
+        if cap.version() >= 2 && base + CAPABILITY_V2_LEN <= cf.len() {
+            cap.has_version_2_registers = true;
+            cap.device_capabilities_2 = cf.dword(base + DEVICE_CAPABILITIES_2);
+            cap.device_control_2 = cf.word(base + DEVICE_CONTROL_2);
+            cap.device_status_2 = cf.word(base + DEVICE_STATUS_2);
+            if cap.has_link() {
+                cap.link_capabilities_2 = cf.dword(base + LINK_CAPABILITIES_2);
+                cap.link_control_2 = cf.word(base + LINK_CONTROL_2);
+                cap.link_status_2 = cf.word(base + LINK_STATUS_2);
+            }
+            if cap.has_slot() {
+                cap.slot_capabilities_2 = cf.dword(base + SLOT_CAPABILITIES_2);
+                cap.slot_control_2 = cf.word(base + SLOT_CONTROL_2);
+                cap.slot_status_2 = cf.word(base + SLOT_STATUS_2);
+            }
+        }
+
+        Some(cap)
+    }
+
+    pub fn version(&self) -> u8 {
+        (self.capabilities & 0xf) as u8
+    }
+
+    pub fn port_type(&self) -> PortType {
+        PortType::from(((self.capabilities >> 4) & 0xf) as u8)
+    }
+
+    pub fn is_slot_implemented(&self) -> bool {
+        self.capabilities & 0x0100 > 0
+    }
+
+    pub fn interrupt_message_number(&self) -> u8 {
+        ((self.capabilities >> 9) & 0x1f) as u8
+    }
+
+    // root complex integrated devices have no link
+    pub fn has_link(&self) -> bool {
+        !matches!(
+            self.port_type(),
+            PortType::RootComplexEndpoint | PortType::RootComplexEventCollector
+        )
+    }
+
+    pub fn has_slot(&self) -> bool {
+        self.is_slot_implemented() && self.port_type().is_downstream_facing()
+    }
+
+    pub fn has_root(&self) -> bool {
+        matches!(
+            self.port_type(),
+            PortType::RootPort | PortType::RootComplexEventCollector
+        )
+    }
+
+    pub fn has_version_2(&self) -> bool {
+        self.has_version_2_registers
+    }
+
+    pub fn max_payload_supported(&self) -> u32 {
+        128 << (self.device_capabilities & 0x7)
+    }
+
+    pub fn max_payload(&self) -> u32 {
+        128 << ((self.device_control >> 5) & 0x7)
+    }
+
+    pub fn max_read_request(&self) -> u32 {
+        128 << ((self.device_control >> 12) & 0x7)
+    }
+
+    pub fn max_link_speed(&self) -> u8 {
+        (self.link_capabilities & 0xf) as u8
+    }
+
+    pub fn max_link_width(&self) -> u8 {
+        ((self.link_capabilities >> 4) & 0x3f) as u8
+    }
+
+    pub fn link_speed(&self) -> u8 {
+        (self.link_status & 0xf) as u8
+    }
+
+    pub fn link_width(&self) -> u8 {
+        ((self.link_status >> 4) & 0x3f) as u8
+    }
+
+    pub fn aspm_support(&self) -> u8 {
+        ((self.link_capabilities >> 10) & 0x3) as u8
+    }
+
+    pub fn aspm_control(&self) -> u8 {
+        (self.link_control & 0x3) as u8
+    }
+}
+
+pub struct PciExpressPrettyPrinter {}
+
+impl Default for PciExpressPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PciExpressPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn print_device(&self, cap: &PciExpressCapability, lines: &mut Vec<String>) {
+        let devcap = cap.device_capabilities;
+        let values = format!(
+            "MaxPayload {} bytes, PhantFunc {}, Latency L0s {}, L1 {}, SlotPowerLimit {}",
+            cap.max_payload_supported(),
+            (devcap >> 3) & 0x3,
+            L0S_ACCEPTABLE_LATENCIES[((devcap >> 6) & 0x7) as usize],
+            L1_ACCEPTABLE_LATENCIES[((devcap >> 9) & 0x7) as usize],
+            format_power_limit((devcap >> 18) & 0xff, (devcap >> 26) & 0x3),
+        );
+        let flags = format_bitvec_flags(devcap, &DEVICE_CAPABILITIES_FIELDS);
+        push_register_lines("DevCap", values, flags, lines);
+
+        let values = format!(
+            "MaxPayload {} bytes, MaxReadReq {} bytes",
+            cap.max_payload(),
+            cap.max_read_request()
+        );
+        let flags = format_bitvec_flags(cap.device_control as u32, &DEVICE_CONTROL_FIELDS);
+        push_register_lines("DevCtl", values, flags, lines);
+
+        let flags = format_bitvec_flags(cap.device_status as u32, &DEVICE_STATUS_FIELDS);
+        push_register_lines("DevSta", String::new(), flags, lines);
+    }
+
+    fn print_link(&self, cap: &PciExpressCapability, lines: &mut Vec<String>) {
+        let lnkcap = cap.link_capabilities;
+        let values = format!(
+            "Port #{}, Speed {}, Width x{}, ASPM {}, Exit Latency L0s {}, L1 {}",
+            lnkcap >> 24,
+            link_speed_name(cap.max_link_speed()),
+            cap.max_link_width(),
+            aspm_name(cap.aspm_support()),
+            L0S_EXIT_LATENCIES[((lnkcap >> 12) & 0x7) as usize],
+            L1_EXIT_LATENCIES[((lnkcap >> 15) & 0x7) as usize],
+        );
+        let flags = format_bitvec_flags(lnkcap, &LINK_CAPABILITIES_FIELDS);
+        push_register_lines("LnkCap", values, flags, lines);
+
+        let values = match cap.aspm_control() {
+            0 => String::from("ASPM Disabled"),
+            aspm => format!("ASPM {} Enabled", aspm_name(aspm)),
+        };
+        let flags = format_bitvec_flags(cap.link_control as u32, &LINK_CONTROL_FIELDS);
+        push_register_lines("LnkCtl", values, flags, lines);
+
+        let values = format!(
+            "Speed {}, Width x{}",
+            link_speed_name(cap.link_speed()),
+            cap.link_width()
+        );
+        let flags = format_bitvec_flags(cap.link_status as u32, &LINK_STATUS_FIELDS);
+        push_register_lines("LnkSta", values, flags, lines);
+    }
+
+    fn print_slot(&self, cap: &PciExpressCapability, lines: &mut Vec<String>) {
+        let sltcap = cap.slot_capabilities;
+        let values = format!(
+            "Slot #{}, PowerLimit {}",
+            sltcap >> 19,
+            format_power_limit((sltcap >> 7) & 0xff, (sltcap >> 15) & 0x3)
+        );
+        let flags = format_bitvec_flags(sltcap, &SLOT_CAPABILITIES_FIELDS);
+        push_register_lines("SltCap", values, flags, lines);
+
+        let sltctl = cap.slot_control;
+        let values = format!(
+            "AttnInd {}, PwrInd {}",
+            INDICATOR_STATES[((sltctl >> 6) & 0x3) as usize],
+            INDICATOR_STATES[((sltctl >> 8) & 0x3) as usize],
+        );
+        let flags = format_bitvec_flags(sltctl as u32, &SLOT_CONTROL_FIELDS);
+        push_register_lines("SltCtl", values, flags, lines);
+
+        let flags = format_bitvec_flags(cap.slot_status as u32, &SLOT_STATUS_FIELDS);
+        push_register_lines("SltSta", String::new(), flags, lines);
+    }
+
+    fn print_root(&self, cap: &PciExpressCapability, lines: &mut Vec<String>) {
+        let flags = format_bitvec_flags(cap.root_control as u32, &ROOT_CONTROL_FIELDS);
+        push_register_lines("RootCtl", String::new(), flags, lines);
+
+        let flags = match cap.root_capabilities & 0x1 {
+            1 => String::from("+CRSVisible"),
+            _ => String::from("-CRSVisible"),
+        };
+        push_register_lines("RootCap", String::new(), flags, lines);
+
+        let values = format!("PME ReqID {:04x}", cap.root_status & 0xffff);
+        let flags = format_bitvec_flags(cap.root_status, &ROOT_STATUS_FIELDS);
+        push_register_lines("RootSta", values, flags, lines);
+    }
+
+    fn print_device_2(&self, cap: &PciExpressCapability, lines: &mut Vec<String>) {
+        let devcap2 = cap.device_capabilities_2;
+        let values = format!(
+            "Completion Timeout: {}, TPHComp {}, OBFF {}, MaxEETLPPrefixes {}",
+            completion_timeout_ranges(devcap2),
+            (devcap2 >> 12) & 0x3,
+            [
+                "Not Supported",
+                "Via message",
+                "Via WAKE#",
+                "Via message/WAKE#"
+            ][((devcap2 >> 18) & 0x3) as usize],
+            match (devcap2 >> 22) & 0x3 {
+                0 => 4,
+                prefixes => prefixes,
+            },
+        );
+        let flags = format_bitvec_flags(devcap2, &DEVICE_CAPABILITIES_2_FIELDS);
+        push_register_lines("DevCap2", values, flags, lines);
+
+        let devctl2 = cap.device_control_2;
+        let values = format!(
+            "Completion Timeout: {}, OBFF {}",
+            completion_timeout_value(devctl2),
+            ["Disabled", "Via message A", "Via message B", "Via WAKE#"]
+                [((devctl2 >> 13) & 0x3) as usize],
+        );
+        let flags = format_bitvec_flags(devctl2 as u32, &DEVICE_CONTROL_2_FIELDS);
+        push_register_lines("DevCtl2", values, flags, lines);
+
+        let values = format!("[0x{:04x}]", cap.device_status_2);
+        lines.push(format!("{:<9}: {}", "DevSta2", values));
+    }
+
+    fn print_link_2(&self, cap: &PciExpressCapability, lines: &mut Vec<String>) {
+        let lnkcap2 = cap.link_capabilities_2;
+        let values = format!(
+            "Supported Link Speeds: {}",
+            supported_link_speeds(lnkcap2 & 0xfe)
+        );
+        let flags = format_bitvec_flags(lnkcap2, &LINK_CAPABILITIES_2_FIELDS);
+        push_register_lines("LnkCap2", values, flags, lines);
+
+        let lnkctl2 = cap.link_control_2;
+        let values = format!(
+            "Target Link Speed {}, Transmit Margin {}, Compliance Preset/De-emphasis {}",
+            link_speed_name((lnkctl2 & 0xf) as u8),
+            (lnkctl2 >> 7) & 0x7,
+            (lnkctl2 >> 12) & 0xf,
+        );
+        let flags = format_bitvec_flags(lnkctl2 as u32, &LINK_CONTROL_2_FIELDS);
+        push_register_lines("LnkCtl2", values, flags, lines);
+
+        let lnksta2 = cap.link_status_2;
+        let values = format!(
+            "Current De-emphasis Level {}",
+            match lnksta2 & 0x1 {
+                1 => "-3.5dB",
+                _ => "-6dB",
+            }
+        );
+        let flags = format_bitvec_flags(lnksta2 as u32, &LINK_STATUS_2_FIELDS);
+        push_register_lines("LnkSta2", values, flags, lines);
+    }
+
+    fn print_slot_2(&self, cap: &PciExpressCapability, lines: &mut Vec<String>) {
+        let flags = match cap.slot_capabilities_2 & 0x1 {
+            1 => String::from("+IbPresDis"),
+            _ => String::from("-IbPresDis"),
+        };
+        push_register_lines("SltCap2", String::new(), flags, lines);
+
+        let values = format!("[0x{:04x}]", cap.slot_control_2);
+        lines.push(format!("{:<9}: {}", "SltCtl2", values));
+        let values = format!("[0x{:04x}]", cap.slot_status_2);
+        lines.push(format!("{:<9}: {}", "SltSta2", values));
+    }
+
+    pub fn print(&self, cap: &PciExpressCapability) -> Vec<String> {
+        let mut lines = vec![];
+
+        let summary = format!(
+            "{} (v{}), MSI {:02x}",
+            cap.port_type().name(),
+            cap.version(),
+            cap.interrupt_message_number()
+        );
+        lines.push(summary);
+
+        self.print_device(cap, &mut lines);
+        if cap.has_link() {
+            self.print_link(cap, &mut lines);
+        }
+        if cap.has_slot() {
+            self.print_slot(cap, &mut lines);
+        }
+        if cap.has_root() {
+            self.print_root(cap, &mut lines);
+        }
+
+        if cap.has_version_2() {
+            self.print_device_2(cap, &mut lines);
+            if cap.has_link() {
+                self.print_link_2(cap, &mut lines);
+            }
+            if cap.has_slot() {
+                self.print_slot_2(cap, &mut lines);
+            }
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a root port with a slot, its capability at `offset` and every register
+    // after the capabilities register set to all ones
+    fn config_space(len: usize, offset: usize, version: u16) -> ConfigSpace {
+        let mut bytes = vec![0; len];
+        let end = len.min(offset + CAPABILITY_V2_LEN);
+        for byte in &mut bytes[offset + DEVICE_CAPABILITIES..end] {
+            *byte = 0xff;
+        }
+        let capabilities = version | 0x4 << 4 | 0x0100;
+        bytes[offset + CAPABILITIES..offset + CAPABILITIES + 2]
+            .copy_from_slice(&capabilities.to_le_bytes());
+        ConfigSpace::from(bytes)
+    }
+
+    fn slot_capability_flags(slot_capabilities: u32) -> String {
+        let mut cap = PciExpressCapability::read(&config_space(0x100, 0x40, 2), 0x40).unwrap();
+        cap.slot_capabilities = slot_capabilities;
+        let lines = PciExpressPrettyPrinter::new().print(&cap);
+        let index = lines
+            .iter()
+            .position(|line| line.starts_with("SltCap"))
+            .unwrap();
+        lines[index + 1].clone()
+    }
+
+    #[test]
+    fn names_hot_plug_surprise_before_hot_plug_capable() {
+        let flags = slot_capability_flags(0x20);
+        assert!(flags.contains("+Surprise"));
+        assert!(flags.contains("-HotPlug"));
+
+        let flags = slot_capability_flags(0x40);
+        assert!(flags.contains("+HotPlug"));
+        assert!(flags.contains("-Surprise"));
+    }
+
+    #[test]
+    fn skips_the_version_2_registers_on_version_1() {
+        let cap = PciExpressCapability::read(&config_space(0x100, 0x40, 1), 0x40).unwrap();
+        assert_eq!(cap.port_type(), PortType::RootPort);
+        assert_eq!(cap.slot_capabilities, 0xffff_ffff);
+        assert_eq!(cap.root_status, 0xffff_ffff);
+        assert!(!cap.has_version_2());
+        assert_eq!(cap.device_capabilities_2, 0);
+        assert_eq!(cap.link_capabilities_2, 0);
+        assert_eq!(cap.slot_capabilities_2, 0);
+        assert_eq!(cap.slot_status_2, 0);
+
+        let lines = PciExpressPrettyPrinter::new().print(&cap);
+        assert!(lines.iter().all(|line| !line.starts_with("DevCap2")));
+    }
+
+    #[test]
+    fn reads_the_version_2_registers() {
+        let cap = PciExpressCapability::read(&config_space(0x100, 0x40, 2), 0x40).unwrap();
+        assert!(cap.has_version_2());
+        assert_eq!(cap.device_capabilities_2, 0xffff_ffff);
+        assert_eq!(cap.slot_status_2, 0xffff);
+    }
+
+    #[test]
+    fn skips_version_2_registers_past_the_end() {
+        // the version 1 registers fit below 0x100, the version 2 ones don't
+        let cap = PciExpressCapability::read(&config_space(0x100, 0xd0, 2), 0xd0).unwrap();
+        assert_eq!(cap.version(), 2);
+        assert!(!cap.has_version_2());
+        assert_eq!(cap.device_capabilities_2, 0);
+
+        assert_eq!(
+            PciExpressCapability::read(&config_space(0x100, 0xe0, 2), 0xe0),
+            None
+        );
+    }
+}
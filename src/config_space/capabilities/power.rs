@@ -0,0 +1,255 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::shared::{
+    format_bitvec_flags, push_register_lines, BitVecFieldDescriptor,
+};
+use crate::config_space::ConfigSpace;
+
+// register offsets relative to the start of the capability
+const CAPABILITIES: usize = 0x02;
+const CONTROL_STATUS: usize = 0x04;
+
+const CAPABILITY_LEN: usize = 0x08;
+
+const AUX_CURRENTS: [&str; 8] = [
+    "0mA", "55mA", "100mA", "160mA", "220mA", "270mA", "320mA", "375mA",
+];
+
+const CAPABILITIES_FIELDS: [BitVecFieldDescriptor; 13] = [
+    // Version
+    BitVecFieldDescriptor {
+        len: 3,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // PME Clock
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PMEClk",
+        is_reserved: false,
+    },
+    // Immediate Readiness on Return to D0
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ImmReady",
+        is_reserved: false,
+    },
+    // Device Specific Initialization
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "DSI",
+        is_reserved: false,
+    },
+    // Aux Current
+    BitVecFieldDescriptor {
+        len: 3,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // D1 Support
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "D1",
+        is_reserved: false,
+    },
+    // D2 Support
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "D2",
+        is_reserved: false,
+    },
+    // PME Support, one bit per state the PME# can be asserted from
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PME-D0",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PME-D1",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PME-D2",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PME-D3hot",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PME-D3cold",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 16,
+        name: "Reserved 3",
+        is_reserved: true,
+    },
+];
+
+const CONTROL_STATUS_FIELDS: [BitVecFieldDescriptor; 6] = [
+    // PowerState
+    BitVecFieldDescriptor {
+        len: 3,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // No_Soft_Reset
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "NoSoftRst",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 4,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // PME_En
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PME-Enable",
+        is_reserved: false,
+    },
+    // Data_Select, Data_Scale
+    BitVecFieldDescriptor {
+        len: 6,
+        name: "Reserved 3",
+        is_reserved: true,
+    },
+    // PME_Status
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PME",
+        is_reserved: false,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerState {
+    D0,
+    D1,
+    D2,
+    D3Hot,
+}
+
+impl From<u16> for PowerState {
+    fn from(value: u16) -> Self {
+        match value & 0x3 {
+            0 => PowerState::D0,
+            1 => PowerState::D1,
+            2 => PowerState::D2,
+            _ => PowerState::D3Hot,
+        }
+    }
+}
+
+impl PowerState {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PowerState::D0 => "D0",
+            PowerState::D1 => "D1",
+            PowerState::D2 => "D2",
+            PowerState::D3Hot => "D3hot",
+        }
+    }
+}
+
+/// The PCI Power Management capability (ID 0x01).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PowerManagementCapability {
+    pub offset: u8,
+    pub capabilities: u16,
+    pub control_status: u16,
+}
+
+impl PowerManagementCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u8) -> Option<Self> {
+        let base = offset as usize;
+        if base + CAPABILITY_LEN > cf.len() {
+            return None;
+        }
+
+        Some(Self {
+            offset,
+            capabilities: cf.word(base + CAPABILITIES),
+            control_status: cf.word(base + CONTROL_STATUS),
+        })
+    }
+
+    pub fn version(&self) -> u8 {
+        (self.capabilities & 0x7) as u8
+    }
+
+    pub fn aux_current(&self) -> &'static str {
+        AUX_CURRENTS[((self.capabilities >> 6) & 0x7) as usize]
+    }
+
+    pub fn supports_d1(&self) -> bool {
+        self.capabilities & 0x0200 > 0
+    }
+
+    pub fn supports_d2(&self) -> bool {
+        self.capabilities & 0x0400 > 0
+    }
+
+    // bit 0 for D0 up to bit 4 for D3cold
+    pub fn pme_support(&self) -> u8 {
+        (self.capabilities >> 11) as u8
+    }
+
+    pub fn power_state(&self) -> PowerState {
+        PowerState::from(self.control_status)
+    }
+
+    pub fn is_no_soft_reset(&self) -> bool {
+        self.control_status & 0x0008 > 0
+    }
+
+    pub fn is_pme_enabled(&self) -> bool {
+        self.control_status & 0x0100 > 0
+    }
+
+    pub fn is_pme_status(&self) -> bool {
+        self.control_status & 0x8000 > 0
+    }
+}
+
+pub struct PowerManagementPrettyPrinter {}
+
+impl Default for PowerManagementPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PowerManagementPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, cap: &PowerManagementCapability) -> Vec<String> {
+        let mut lines = vec![];
+
+        let values = format!(
+            "Version {}, AuxCurrent {}",
+            cap.version(),
+            cap.aux_current()
+        );
+        let flags = format_bitvec_flags(cap.capabilities as u32, &CAPABILITIES_FIELDS);
+        push_register_lines("PMC", values, flags, &mut lines);
+
+        let values = format!("PowerState {}", cap.power_state().name());
+        let flags = format_bitvec_flags(cap.control_status as u32, &CONTROL_STATUS_FIELDS);
+        push_register_lines("PMCSR", values, flags, &mut lines);
+
+        lines
+    }
+}
@@ -0,0 +1,71 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::ConfigSpace;
+
+// register offsets relative to the start of the capability
+const ADDRESS: usize = 0x02;
+const DATA: usize = 0x04;
+
+const CAPABILITY_LEN: usize = 0x08;
+
+/// The Vital Product Data capability (ID 0x03). The VPD itself is read one
+/// dword at a time through these registers, use `VitalProductData` on the
+/// `vpd` file in sysfs to see its contents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VpdCapability {
+    pub offset: u8,
+    pub address: u16,
+    pub data: u32,
+}
+
+impl VpdCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u8) -> Option<Self> {
+        let base = offset as usize;
+        if base + CAPABILITY_LEN > cf.len() {
+            return None;
+        }
+
+        Some(Self {
+            offset,
+            address: cf.word(base + ADDRESS),
+            data: cf.dword(base + DATA),
+        })
+    }
+
+    pub fn vpd_address(&self) -> u16 {
+        self.address & 0x7fff
+    }
+
+    // set by the device when a read completes, by software to start a write
+    pub fn flag(&self) -> bool {
+        self.address & 0x8000 > 0
+    }
+}
+
+pub struct VpdCapabilityPrettyPrinter {}
+
+impl Default for VpdCapabilityPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VpdCapabilityPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, cap: &VpdCapability) -> Vec<String> {
+        let flag = match cap.flag() {
+            true => "+",
+            false => "-",
+        };
+        vec![
+            format!("{:<9}: 0x{:04x}, {}F", "Address", cap.vpd_address(), flag),
+            format!("{:<9}: 0x{:08x}", "Data", cap.data),
+        ]
+    }
+}
@@ -0,0 +1,23 @@
+use alloc::string::String;
+
+use crate::config_space::database::tables;
+use crate::config_space::ids::PciIds;
+use crate::config_space::shared::lookup_name;
+
+pub fn class_name(class: u8) -> Option<&'static str> {
+    lookup_name(tables().classes, &class)
+}
+
+pub fn subclass_name(class: u8, subclass: u8) -> Option<&'static str> {
+    lookup_name(tables().subclasses, &(class, subclass))
+}
+
+pub fn prog_if_name(class: u8, subclass: u8, prog_if: u8) -> Option<&'static str> {
+    lookup_name(tables().prog_ifs, &(class, subclass, prog_if))
+}
+
+/// Describes a class code the way lspci does, as in "Serial bus controller /
+/// USB controller, prog-if 30 [XHCI]", from the embedded tables only.
+pub fn describe_class(class: u8, subclass: u8, prog_if: u8) -> String {
+    PciIds::new().describe_class(class, subclass, prog_if)
+}
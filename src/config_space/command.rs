@@ -1,9 +1,6 @@
-use alloc::vec;
-use alloc::{format, string::String, vec::Vec};
+use alloc::string::String;
 
-use crate::config_space::shared::format_flags_line;
-
-use super::shared::BitVecFieldDescriptor;
+use crate::config_space::shared::{format_bitvec_flags, BitVecFieldDescriptor};
 
 const COMMAND_FIELDS: [BitVecFieldDescriptor; 12] = [
     // I/O Space
@@ -95,32 +92,6 @@ impl CommandPrettyPrinter {
     }
 
     pub fn print(&self, cmd: &CommandRegister) -> String {
-        let mut offset = 0;
-        let mut fields_enabled: Vec<String> = vec![];
-        let mut fields_disabled: Vec<String> = vec![];
-
-        for desc in COMMAND_FIELDS {
-            let enabled = (cmd.vector >> offset) & 0x1;
-
-            if desc.is_reserved {
-                offset += desc.len;
-                continue;
-            }
-
-            match enabled {
-                1 => {
-                    let field = format!("+{}", desc.name);
-                    fields_enabled.push(field);
-                }
-                _ => {
-                    let field = format!("-{}", desc.name);
-                    fields_disabled.push(field);
-                }
-            };
-
-            offset += desc.len;
-        }
-
-        format_flags_line(fields_enabled, fields_disabled)
+        format_bitvec_flags(cmd.vector as u32, &COMMAND_FIELDS)
     }
 }
@@ -0,0 +1,179 @@
+// The ID database embedded at build time from support/pci.ids, as generated
+// by build.rs. Which form it takes depends on the `ids` and `ids-compressed`
+// features; without `ids` every table is empty.
+
+// vendor, device, subsystem vendor and subsystem
+type SubsystemKey = (u16, u16, u16, u16);
+
+pub struct Tables {
+    pub vendors: &'static [(u16, &'static str)],
+    pub devices: &'static [((u16, u16), &'static str)],
+    pub subsystems: &'static [(SubsystemKey, &'static str)],
+    pub classes: &'static [(u8, &'static str)],
+    pub subclasses: &'static [((u8, u8), &'static str)],
+    pub prog_ifs: &'static [((u8, u8, u8), &'static str)],
+}
+
+#[cfg(feature = "ids")]
+include!(concat!(env!("OUT_DIR"), "/version.rs"));
+
+#[cfg(all(feature = "ids", not(feature = "ids-compressed")))]
+mod embedded {
+    use super::{SubsystemKey, Tables};
+
+    include!(concat!(env!("OUT_DIR"), "/tables.rs"));
+
+    pub fn tables() -> &'static Tables {
+        static TABLES: Tables = Tables {
+            vendors: &VENDORS,
+            devices: &DEVICES,
+            subsystems: &SUBSYSTEMS,
+            classes: &CLASSES,
+            subclasses: &SUBCLASSES,
+            prog_ifs: &PROG_IFS,
+        };
+        &TABLES
+    }
+}
+
+#[cfg(feature = "ids-compressed")]
+mod embedded {
+    use alloc::vec::Vec;
+
+    use super::Tables;
+
+    static DEFLATED: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/tables.deflate"));
+    static TABLES: spin::Once<Tables> = spin::Once::new();
+
+    // a section is a line with the number of entries, then a line per entry
+    // holding a packed key in hex, a tab and the name. build.rs checks the
+    // blob inflates to what it wrote, so a bad one is a bug.
+    fn parse_section<K>(
+        lines: &mut impl Iterator<Item = &'static str>,
+        unpack: impl Fn(u64) -> K,
+    ) -> &'static [(K, &'static str)] {
+        let count: usize = lines
+            .next()
+            .and_then(|line| line.parse().ok())
+            .expect("embedded ID tables: bad section length");
+        let entries: Vec<(K, &'static str)> = lines
+            .take(count)
+            .map(|line| {
+                let (key, name) = line
+                    .split_once('\t')
+                    .expect("embedded ID tables: bad entry");
+                let key = u64::from_str_radix(key, 16).expect("embedded ID tables: bad key");
+                (unpack(key), name)
+            })
+            .collect();
+        assert_eq!(
+            entries.len(),
+            count,
+            "embedded ID tables: truncated section"
+        );
+        entries.leak()
+    }
+
+    // inflated on first use and kept for the life of the program
+    fn inflate() -> Tables {
+        let bytes = miniz_oxide::inflate::decompress_to_vec(DEFLATED)
+            .expect("embedded ID tables: bad deflate stream");
+        let text = core::str::from_utf8(bytes.leak()).expect("embedded ID tables: not UTF-8");
+        let lines = &mut text.lines();
+
+        Tables {
+            vendors: parse_section(lines, |key| key as u16),
+            devices: parse_section(lines, |key| ((key >> 16) as u16, key as u16)),
+            subsystems: parse_section(lines, |key| {
+                (
+                    (key >> 48) as u16,
+                    (key >> 32) as u16,
+                    (key >> 16) as u16,
+                    key as u16,
+                )
+            }),
+            classes: parse_section(lines, |key| key as u8),
+            subclasses: parse_section(lines, |key| ((key >> 8) as u8, key as u8)),
+            prog_ifs: parse_section(lines, |key| {
+                ((key >> 16) as u8, (key >> 8) as u8, key as u8)
+            }),
+        }
+    }
+
+    pub fn tables() -> &'static Tables {
+        TABLES.call_once(inflate)
+    }
+}
+
+#[cfg(not(feature = "ids"))]
+mod embedded {
+    use super::Tables;
+
+    pub fn tables() -> &'static Tables {
+        static TABLES: Tables = Tables {
+            vendors: &[],
+            devices: &[],
+            subsystems: &[],
+            classes: &[],
+            subclasses: &[],
+            prog_ifs: &[],
+        };
+        &TABLES
+    }
+}
+
+pub use embedded::tables;
+
+/// The version of the pci.ids file the embedded names come from, as in
+/// "2021.09.19", or `None` if the crate was built without the `ids` feature.
+pub fn id_database_version() -> Option<&'static str> {
+    #[cfg(feature = "ids")]
+    return Some(VERSION);
+    #[cfg(not(feature = "ids"))]
+    return None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config_space::classes::{class_name, prog_if_name, subclass_name};
+    use crate::config_space::devices::device_name;
+    use crate::config_space::subsystems::subsystem_name;
+    use crate::config_space::vendors::vendor_name;
+
+    // names as they appear in support/pci.ids, which the inflated tables
+    // have to give just like the plain ones
+    #[cfg(feature = "ids")]
+    #[test]
+    fn embeds_the_names_from_pci_ids() {
+        assert_eq!(id_database_version(), Some("2021.09.19"));
+        assert_eq!(vendor_name(0x8086), Some("Intel Corporation"));
+        assert_eq!(vendor_name(0x10de), Some("NVIDIA Corporation"));
+        assert_eq!(
+            device_name(0x8086, 0x1533),
+            Some("I210 Gigabit Network Connection")
+        );
+        assert_eq!(device_name(0x10de, 0x1eb8), Some("TU104GL [Tesla T4]"));
+        assert_eq!(
+            subsystem_name(0x8086, 0x1533, 0x103c, 0x0003),
+            Some("Ethernet I210-T1 GbE NIC")
+        );
+        assert_eq!(class_name(0x02), Some("Network controller"));
+        assert_eq!(subclass_name(0x02, 0x00), Some("Ethernet controller"));
+        assert_eq!(prog_if_name(0x01, 0x08, 0x02), Some("NVM Express"));
+        assert_eq!(vendor_name(0x0002), None);
+    }
+
+    #[cfg(not(feature = "ids"))]
+    #[test]
+    fn has_no_names_without_the_database() {
+        assert_eq!(id_database_version(), None);
+        assert_eq!(vendor_name(0x8086), None);
+        assert_eq!(device_name(0x8086, 0x1533), None);
+        assert_eq!(subsystem_name(0x8086, 0x1533, 0x103c, 0x0003), None);
+        assert_eq!(class_name(0x02), None);
+        assert_eq!(subclass_name(0x02, 0x00), None);
+        assert_eq!(prog_if_name(0x01, 0x08, 0x02), None);
+        assert!(tables().vendors.is_empty());
+    }
+}
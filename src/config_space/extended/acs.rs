@@ -0,0 +1,232 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::shared::{
+    format_bitvec_flags, push_register_lines, BitVecFieldDescriptor,
+};
+use crate::config_space::ConfigSpace;
+
+// register offsets relative to the start of the capability
+const CAPABILITIES: usize = 0x04;
+const CONTROL: usize = 0x06;
+const EGRESS_CONTROL_VECTOR: usize = 0x08;
+
+const CAPABILITY_LEN: usize = 0x08;
+
+// the controls that keep peer-to-peer traffic going through the root complex
+// (and so through the IOMMU), as required by Linux for separate IOMMU groups
+const ISOLATION_CONTROLS: [(u16, &str); 4] = [
+    (0x0001, "SrcValid"),
+    (0x0004, "ReqRedir"),
+    (0x0008, "CmpltRedir"),
+    (0x0010, "UpstreamFwd"),
+];
+
+const ACS_FIELDS: [BitVecFieldDescriptor; 8] = [
+    // ACS Source Validation
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "SrcValid",
+        is_reserved: false,
+    },
+    // ACS Translation Blocking
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "TransBlk",
+        is_reserved: false,
+    },
+    // ACS P2P Request Redirect
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ReqRedir",
+        is_reserved: false,
+    },
+    // ACS P2P Completion Redirect
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CmpltRedir",
+        is_reserved: false,
+    },
+    // ACS Upstream Forwarding
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "UpstreamFwd",
+        is_reserved: false,
+    },
+    // ACS P2P Egress Control
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "EgressCtrl",
+        is_reserved: false,
+    },
+    // ACS Direct Translated P2P
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "DirectTrans",
+        is_reserved: false,
+    },
+    // Enhanced Capability / I/O Request Blocking and beyond
+    BitVecFieldDescriptor {
+        len: 9,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+/// The Access Control Services extended capability (ID 0x000d).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccessControlServicesCapability {
+    pub offset: u16,
+    pub capabilities: u16,
+    pub control: u16,
+    // only present with egress control, first dword shown
+    pub egress_control_vector: Option<u32>,
+}
+
+impl AccessControlServicesCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u16) -> Option<Self> {
+        let base = offset as usize;
+        if base + CAPABILITY_LEN > cf.len() {
+            return None;
+        }
+
+        let capabilities = cf.word(base + CAPABILITIES);
+        let egress_control_vector = match capabilities & 0x0020 > 0 {
+            true if base + EGRESS_CONTROL_VECTOR + 4 <= cf.len() => {
+                Some(cf.dword(base + EGRESS_CONTROL_VECTOR))
+            }
+            _ => None,
+        };
+
+        Some(Self {
+            offset,
+            capabilities,
+            control: cf.word(base + CONTROL),
+            egress_control_vector,
+        })
+    }
+
+    // encoded as 0 meaning 256
+    pub fn egress_control_vector_size(&self) -> u16 {
+        match self.capabilities >> 8 {
+            0 => 256,
+            size => size,
+        }
+    }
+
+    /// The isolation controls that are implemented but not enabled. Controls
+    /// the function doesn't implement aren't needed for isolation.
+    pub fn missing_isolation(&self) -> Vec<&'static str> {
+        ISOLATION_CONTROLS
+            .iter()
+            .filter(|(mask, _)| self.capabilities & mask > 0 && self.control & mask == 0)
+            .map(|(_, name)| *name)
+            .collect()
+    }
+
+    pub fn is_isolating(&self) -> bool {
+        self.missing_isolation().is_empty()
+    }
+}
+
+pub struct AccessControlServicesPrettyPrinter {}
+
+impl Default for AccessControlServicesPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccessControlServicesPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, cap: &AccessControlServicesCapability) -> Vec<String> {
+        let mut lines = vec![];
+
+        let values = match cap.egress_control_vector {
+            Some(_) => format!("EgressVectorSize {}", cap.egress_control_vector_size()),
+            None => String::new(),
+        };
+        let flags = format_bitvec_flags(cap.capabilities as u32, &ACS_FIELDS);
+        push_register_lines("ACSCap", values, flags, &mut lines);
+
+        let flags = format_bitvec_flags(cap.control as u32, &ACS_FIELDS);
+        push_register_lines("ACSCtl", String::new(), flags, &mut lines);
+
+        if let Some(vector) = cap.egress_control_vector {
+            lines.push(format!("{:<9}: 0x{:08x}", "EgressCtl", vector));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // an ACS capability at 0x100 followed by an egress control vector
+    fn config_space(len: usize, capabilities: u16, control: u16) -> ConfigSpace {
+        let mut bytes = vec![0; 0x1000];
+        bytes[0x104..0x106].copy_from_slice(&capabilities.to_le_bytes());
+        bytes[0x106..0x108].copy_from_slice(&control.to_le_bytes());
+        bytes[0x108..0x10c].copy_from_slice(&0x0000_00f0u32.to_le_bytes());
+        bytes.truncate(len);
+        ConfigSpace::from(bytes)
+    }
+
+    fn read(capabilities: u16, control: u16) -> AccessControlServicesCapability {
+        AccessControlServicesCapability::read(&config_space(0x1000, capabilities, control), 0x100)
+            .unwrap()
+    }
+
+    #[test]
+    fn lists_the_disabled_isolation_controls() {
+        // SrcValid, ReqRedir, CmpltRedir and UpstreamFwd implemented
+        let acs = read(0x001d, 0x0001);
+        assert_eq!(
+            acs.missing_isolation(),
+            vec!["ReqRedir", "CmpltRedir", "UpstreamFwd"]
+        );
+        assert!(!acs.is_isolating());
+
+        assert!(read(0x001d, 0x001d).is_isolating());
+    }
+
+    #[test]
+    fn ignores_controls_that_are_not_implemented() {
+        // only SrcValid and TransBlk implemented, with TransBlk off
+        let acs = read(0x0003, 0x0001);
+        assert!(acs.missing_isolation().is_empty());
+        assert!(acs.is_isolating());
+    }
+
+    #[test]
+    fn reads_the_egress_control_vector() {
+        assert_eq!(read(0x001d, 0x001d).egress_control_vector, None);
+
+        // egress control with a vector size of 8
+        let acs = read(0x0820, 0);
+        assert_eq!(acs.egress_control_vector, Some(0xf0));
+        assert_eq!(acs.egress_control_vector_size(), 8);
+        assert_eq!(read(0x0020, 0).egress_control_vector_size(), 256);
+
+        let lines = AccessControlServicesPrettyPrinter::new().print(&acs);
+        assert_eq!(lines[0], "ACSCap   : EgressVectorSize 8");
+        assert_eq!(lines[lines.len() - 1], "EgressCtl: 0x000000f0");
+    }
+
+    #[test]
+    fn fails_past_the_end() {
+        let cf = config_space(0x108, 0x0020, 0);
+        let acs = AccessControlServicesCapability::read(&cf, 0x100).unwrap();
+        assert_eq!(acs.egress_control_vector, None);
+
+        let cf = config_space(0x104, 0x0020, 0);
+        assert_eq!(AccessControlServicesCapability::read(&cf, 0x100), None);
+    }
+}
@@ -0,0 +1,579 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::shared::{
+    format_bitvec_flags, push_register_lines, BitVecFieldDescriptor,
+};
+use crate::config_space::ConfigSpace;
+
+// register offsets relative to the start of the capability
+const UNCORRECTABLE_STATUS: usize = 0x04;
+const UNCORRECTABLE_MASK: usize = 0x08;
+const UNCORRECTABLE_SEVERITY: usize = 0x0c;
+const CORRECTABLE_STATUS: usize = 0x10;
+const CORRECTABLE_MASK: usize = 0x14;
+const CAPABILITIES_CONTROL: usize = 0x18;
+const HEADER_LOG: usize = 0x1c;
+const ROOT_ERROR_COMMAND: usize = 0x2c;
+const ROOT_ERROR_STATUS: usize = 0x30;
+const ERROR_SOURCE_ID: usize = 0x34;
+
+const CAPABILITY_LEN: usize = 0x2c;
+const ROOT_CAPABILITY_LEN: usize = 0x38;
+
+const UNCORRECTABLE_FIELDS: [BitVecFieldDescriptor; 20] = [
+    BitVecFieldDescriptor {
+        len: 4,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Data Link Protocol Error
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "DLP",
+        is_reserved: false,
+    },
+    // Surprise Down Error
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "SDES",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 6,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // Poisoned TLP Received
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "TLP",
+        is_reserved: false,
+    },
+    // Flow Control Protocol Error
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "FCP",
+        is_reserved: false,
+    },
+    // Completion Timeout
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CmpltTO",
+        is_reserved: false,
+    },
+    // Completer Abort
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CmpltAbrt",
+        is_reserved: false,
+    },
+    // Unexpected Completion
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "UnxCmplt",
+        is_reserved: false,
+    },
+    // Receiver Overflow
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "RxOF",
+        is_reserved: false,
+    },
+    // Malformed TLP
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MalfTLP",
+        is_reserved: false,
+    },
+    // ECRC Error
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ECRC",
+        is_reserved: false,
+    },
+    // Unsupported Request Error
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "UnsupReq",
+        is_reserved: false,
+    },
+    // ACS Violation
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ACSViol",
+        is_reserved: false,
+    },
+    // Uncorrectable Internal Error
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "UncorrIntErr",
+        is_reserved: false,
+    },
+    // MC Blocked TLP
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "BlockedTLP",
+        is_reserved: false,
+    },
+    // AtomicOp Egress Blocked
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "AtomicOpBlocked",
+        is_reserved: false,
+    },
+    // TLP Prefix Blocked Error
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "TLPBlockedErr",
+        is_reserved: false,
+    },
+    // Poisoned TLP Egress Blocked
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PoisonTLPBlocked",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 5,
+        name: "Reserved 3",
+        is_reserved: true,
+    },
+];
+
+const CORRECTABLE_FIELDS: [BitVecFieldDescriptor; 11] = [
+    // Receiver Error
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "RxErr",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 5,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Bad TLP
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "BadTLP",
+        is_reserved: false,
+    },
+    // Bad DLLP
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "BadDLLP",
+        is_reserved: false,
+    },
+    // REPLAY_NUM Rollover
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Rollover",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 3,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // Replay Timer Timeout
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Timeout",
+        is_reserved: false,
+    },
+    // Advisory Non-Fatal Error
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "AdvNonFatalErr",
+        is_reserved: false,
+    },
+    // Corrected Internal Error
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CorrIntErr",
+        is_reserved: false,
+    },
+    // Header Log Overflow
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "HeaderOF",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 16,
+        name: "Reserved 3",
+        is_reserved: true,
+    },
+];
+
+const CAPABILITIES_CONTROL_FIELDS: [BitVecFieldDescriptor; 10] = [
+    // First Error Pointer
+    BitVecFieldDescriptor {
+        len: 5,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // ECRC Generation Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ECRCGenCap",
+        is_reserved: false,
+    },
+    // ECRC Generation Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ECRCGenEn",
+        is_reserved: false,
+    },
+    // ECRC Check Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ECRCChkCap",
+        is_reserved: false,
+    },
+    // ECRC Check Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ECRCChkEn",
+        is_reserved: false,
+    },
+    // Multiple Header Recording Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MultHdrRecCap",
+        is_reserved: false,
+    },
+    // Multiple Header Recording Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MultHdrRecEn",
+        is_reserved: false,
+    },
+    // TLP Prefix Log Present
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "TLPPfxPres",
+        is_reserved: false,
+    },
+    // Completion Timeout Prefix/Header Log Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "HdrLogCap",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 19,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+];
+
+const ROOT_COMMAND_FIELDS: [BitVecFieldDescriptor; 4] = [
+    // Correctable Error Reporting Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CERptEn",
+        is_reserved: false,
+    },
+    // Non-Fatal Error Reporting Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "NFERptEn",
+        is_reserved: false,
+    },
+    // Fatal Error Reporting Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "FERptEn",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 29,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+const ROOT_STATUS_FIELDS: [BitVecFieldDescriptor; 8] = [
+    // ERR_COR Received
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CERcvd",
+        is_reserved: false,
+    },
+    // Multiple ERR_COR Received
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MultCERcvd",
+        is_reserved: false,
+    },
+    // ERR_FATAL/NONFATAL Received
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "UERcvd",
+        is_reserved: false,
+    },
+    // Multiple ERR_FATAL/NONFATAL Received
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MultUERcvd",
+        is_reserved: false,
+    },
+    // First Uncorrectable Fatal
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "FirstFatal",
+        is_reserved: false,
+    },
+    // Non-Fatal Error Messages Received
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "NonFatalMsg",
+        is_reserved: false,
+    },
+    // Fatal Error Messages Received
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "FatalMsg",
+        is_reserved: false,
+    },
+    // Advanced Error Interrupt Message Number
+    BitVecFieldDescriptor {
+        len: 25,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+fn format_requester_id(id: u16) -> String {
+    format!("{:02x}:{:02x}.{:x}", id >> 8, (id >> 3) & 0x1f, id & 0x7)
+}
+
+/// The Advanced Error Reporting extended capability (ID 0x0001). The root
+/// error registers are only present on root ports and root complex event
+/// collectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdvancedErrorReportingCapability {
+    pub offset: u16,
+    pub uncorrectable_status: u32,
+    pub uncorrectable_mask: u32,
+    pub uncorrectable_severity: u32,
+    pub correctable_status: u32,
+    pub correctable_mask: u32,
+    pub capabilities_control: u32,
+    pub header_log: [u32; 4],
+    pub root_error_command: Option<u32>,
+    pub root_error_status: Option<u32>,
+    pub error_source_id: Option<u32>,
+}
+
+impl AdvancedErrorReportingCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u16, has_root: bool) -> Option<Self> {
+        let base = offset as usize;
+        let len = match has_root {
+            true => ROOT_CAPABILITY_LEN,
+            false => CAPABILITY_LEN,
+        };
+        if base + len > cf.len() {
+            return None;
+        }
+
+        let mut header_log = [0; 4];
+        for (index, dword) in header_log.iter_mut().enumerate() {
+            *dword = cf.dword(base + HEADER_LOG + index * 4);
+        }
+
+        let root_register = |register: usize| match has_root {
+            true => Some(cf.dword(base + register)),
+            false => None,
+        };
+
+        Some(Self {
+            offset,
+            uncorrectable_status: cf.dword(base + UNCORRECTABLE_STATUS),
+            uncorrectable_mask: cf.dword(base + UNCORRECTABLE_MASK),
+            uncorrectable_severity: cf.dword(base + UNCORRECTABLE_SEVERITY),
+            correctable_status: cf.dword(base + CORRECTABLE_STATUS),
+            correctable_mask: cf.dword(base + CORRECTABLE_MASK),
+            capabilities_control: cf.dword(base + CAPABILITIES_CONTROL),
+            header_log,
+            root_error_command: root_register(ROOT_ERROR_COMMAND),
+            root_error_status: root_register(ROOT_ERROR_STATUS),
+            error_source_id: root_register(ERROR_SOURCE_ID),
+        })
+    }
+
+    pub fn first_error_pointer(&self) -> u8 {
+        (self.capabilities_control & 0x1f) as u8
+    }
+
+    pub fn is_ecrc_generation_enabled(&self) -> bool {
+        self.capabilities_control & 0x40 > 0
+    }
+
+    pub fn is_ecrc_check_enabled(&self) -> bool {
+        self.capabilities_control & 0x100 > 0
+    }
+}
+
+pub struct AdvancedErrorReportingPrettyPrinter {}
+
+impl Default for AdvancedErrorReportingPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AdvancedErrorReportingPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, cap: &AdvancedErrorReportingCapability) -> Vec<String> {
+        let mut lines = vec![];
+
+        let uncorrectable = [
+            ("UESta", cap.uncorrectable_status),
+            ("UEMsk", cap.uncorrectable_mask),
+            ("UESvrt", cap.uncorrectable_severity),
+        ];
+        for (name, value) in uncorrectable {
+            let flags = format_bitvec_flags(value, &UNCORRECTABLE_FIELDS);
+            push_register_lines(name, String::new(), flags, &mut lines);
+        }
+
+        let correctable = [
+            ("CESta", cap.correctable_status),
+            ("CEMsk", cap.correctable_mask),
+        ];
+        for (name, value) in correctable {
+            let flags = format_bitvec_flags(value, &CORRECTABLE_FIELDS);
+            push_register_lines(name, String::new(), flags, &mut lines);
+        }
+
+        let values = format!("First Error Pointer {:02x}", cap.first_error_pointer());
+        let flags = format_bitvec_flags(cap.capabilities_control, &CAPABILITIES_CONTROL_FIELDS);
+        push_register_lines("AERCap", values, flags, &mut lines);
+
+        let header_log: Vec<String> = cap
+            .header_log
+            .iter()
+            .map(|dword| format!("{:08x}", dword))
+            .collect();
+        lines.push(format!("{:<9}: {}", "HeaderLog", header_log.join(" ")));
+
+        if let Some(command) = cap.root_error_command {
+            let flags = format_bitvec_flags(command, &ROOT_COMMAND_FIELDS);
+            push_register_lines("RootCmd", String::new(), flags, &mut lines);
+        }
+
+        if let Some(status) = cap.root_error_status {
+            let values = format!("Interrupt Message {}", status >> 27);
+            let flags = format_bitvec_flags(status, &ROOT_STATUS_FIELDS);
+            push_register_lines("RootSta", values, flags, &mut lines);
+        }
+
+        if let Some(source) = cap.error_source_id {
+            lines.push(format!(
+                "{:<9}: ERR_COR {}, ERR_FATAL/NONFATAL {}",
+                "ErrorSrc",
+                format_requester_id((source & 0xffff) as u16),
+                format_requester_id((source >> 16) as u16),
+            ));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OFFSET: u16 = 0x100;
+
+    // an AER capability at 0x100 with the given (register, value) pairs
+    fn config_space(len: usize, registers: &[(usize, u32)]) -> ConfigSpace {
+        let mut bytes = vec![0; len];
+        for (register, value) in registers {
+            let offset = OFFSET as usize + register;
+            bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+        ConfigSpace::from(bytes)
+    }
+
+    fn print(cap: &AdvancedErrorReportingCapability) -> Vec<String> {
+        AdvancedErrorReportingPrettyPrinter::new().print(cap)
+    }
+
+    #[test]
+    fn names_the_uncorrectable_status_bits() {
+        // DLP, SDES, TLP, FCP, CmpltTO and UnxCmplt
+        let status = 1 << 4 | 1 << 5 | 1 << 12 | 1 << 13 | 1 << 14 | 1 << 16;
+        let cf = config_space(0x1000, &[(UNCORRECTABLE_STATUS, status)]);
+        let cap = AdvancedErrorReportingCapability::read(&cf, OFFSET, false).unwrap();
+        assert_eq!(cap.uncorrectable_status, status);
+        assert!(print(&cap)[0]
+            .starts_with("UESta    : +DLP +SDES +TLP +FCP +CmpltTO +UnxCmplt -CmpltAbrt -RxOF"));
+    }
+
+    #[test]
+    fn names_the_correctable_status_bits() {
+        // RxErr and BadTLP
+        let cf = config_space(0x1000, &[(CORRECTABLE_STATUS, 1 << 0 | 1 << 6)]);
+        let cap = AdvancedErrorReportingCapability::read(&cf, OFFSET, false).unwrap();
+        assert!(print(&cap)[3].starts_with("CESta    : +RxErr +BadTLP -BadDLLP"));
+    }
+
+    #[test]
+    fn reads_the_first_error_pointer() {
+        // pointing at UnxCmplt, with ECRC generation and checking enabled
+        let cf = config_space(0x1000, &[(CAPABILITIES_CONTROL, 0x10 | 0x40 | 0x100)]);
+        let cap = AdvancedErrorReportingCapability::read(&cf, OFFSET, false).unwrap();
+        assert_eq!(cap.first_error_pointer(), 0x10);
+        assert!(cap.is_ecrc_generation_enabled());
+        assert!(cap.is_ecrc_check_enabled());
+        assert_eq!(print(&cap)[5], "AERCap   : First Error Pointer 10");
+    }
+
+    #[test]
+    fn reads_the_root_registers_only_on_root_ports() {
+        let registers = [
+            (ROOT_ERROR_COMMAND, 0x7),
+            (ROOT_ERROR_STATUS, 0x1 | 3 << 27),
+            // ERR_COR from 00:1c.0, ERR_FATAL/NONFATAL from 03:00.1
+            (ERROR_SOURCE_ID, 0x0301 << 16 | 0x00e0),
+        ];
+        let cf = config_space(0x1000, &registers);
+
+        let cap = AdvancedErrorReportingCapability::read(&cf, OFFSET, false).unwrap();
+        assert_eq!(cap.root_error_command, None);
+        assert_eq!(cap.error_source_id, None);
+        assert_eq!(print(&cap).len(), 8);
+
+        let cap = AdvancedErrorReportingCapability::read(&cf, OFFSET, true).unwrap();
+        assert_eq!(cap.root_error_command, Some(0x7));
+        let lines = print(&cap);
+        assert_eq!(lines[8], "RootCmd  : +CERptEn +NFERptEn +FERptEn");
+        assert_eq!(lines[9], "RootSta  : Interrupt Message 3");
+        assert!(lines[10].trim_start().starts_with("+CERcvd -MultCERcvd"));
+        assert_eq!(
+            lines[11],
+            "ErrorSrc : ERR_COR 00:1c.0, ERR_FATAL/NONFATAL 03:00.1"
+        );
+    }
+
+    #[test]
+    fn fails_past_the_end() {
+        // long enough for an endpoint, not for the root registers
+        let cf = config_space(OFFSET as usize + CAPABILITY_LEN, &[]);
+        assert!(AdvancedErrorReportingCapability::read(&cf, OFFSET, false).is_some());
+        assert_eq!(
+            AdvancedErrorReportingCapability::read(&cf, OFFSET, true),
+            None
+        );
+    }
+}
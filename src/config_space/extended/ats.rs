@@ -0,0 +1,129 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::shared::{
+    format_bitvec_flags, push_register_lines, BitVecFieldDescriptor,
+};
+use crate::config_space::{format_size, ConfigSpace};
+
+// register offsets relative to the start of the capability
+const CAPABILITY: usize = 0x04;
+const CONTROL: usize = 0x06;
+
+const CAPABILITY_LEN: usize = 0x08;
+
+const CAPABILITIES_FIELDS: [BitVecFieldDescriptor; 5] = [
+    // Invalidate Queue Depth
+    BitVecFieldDescriptor {
+        len: 5,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Page Aligned Request
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PageAligned",
+        is_reserved: false,
+    },
+    // Global Invalidate Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "GlobalInvalidate",
+        is_reserved: false,
+    },
+    // Relaxed Ordering Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "RelaxedOrdering",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 8,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+];
+
+const CONTROL_FIELDS: [BitVecFieldDescriptor; 2] = [
+    // Smallest Translation Unit
+    BitVecFieldDescriptor {
+        len: 15,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // ATS Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Enable",
+        is_reserved: false,
+    },
+];
+
+/// The Address Translation Services extended capability (ID 0x000f).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddressTranslationServicesCapability {
+    pub offset: u16,
+    pub capability: u16,
+    pub control: u16,
+}
+
+impl AddressTranslationServicesCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u16) -> Option<Self> {
+        let base = offset as usize;
+        if base + CAPABILITY_LEN > cf.len() {
+            return None;
+        }
+
+        Some(Self {
+            offset,
+            capability: cf.word(base + CAPABILITY),
+            control: cf.word(base + CONTROL),
+        })
+    }
+
+    // encoded as 0 meaning 32
+    pub fn invalidate_queue_depth(&self) -> u8 {
+        match self.capability & 0x1f {
+            0 => 32,
+            depth => depth as u8,
+        }
+    }
+
+    // the smallest translation unit in bytes, 4K << STU
+    pub fn smallest_translation_unit(&self) -> u64 {
+        0x1000 << (self.control & 0x1f)
+    }
+}
+
+pub struct AddressTranslationServicesPrettyPrinter {}
+
+impl Default for AddressTranslationServicesPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AddressTranslationServicesPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, cap: &AddressTranslationServicesCapability) -> Vec<String> {
+        let mut lines = vec![];
+
+        let values = format!("Invalidate Queue Depth {}", cap.invalidate_queue_depth());
+        let flags = format_bitvec_flags(cap.capability as u32, &CAPABILITIES_FIELDS);
+        push_register_lines("ATSCap", values, flags, &mut lines);
+
+        let values = format!(
+            "Smallest Translation Unit {}",
+            format_size(cap.smallest_translation_unit())
+        );
+        let flags = format_bitvec_flags(cap.control as u32, &CONTROL_FIELDS);
+        push_register_lines("ATSCtl", values, flags, &mut lines);
+
+        lines
+    }
+}
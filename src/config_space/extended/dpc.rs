@@ -0,0 +1,391 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::shared::{
+    format_bitvec_flags, push_register_lines, BitVecFieldDescriptor,
+};
+use crate::config_space::ConfigSpace;
+
+// register offsets relative to the start of the capability
+const CAPABILITY: usize = 0x04;
+const CONTROL: usize = 0x06;
+const STATUS: usize = 0x08;
+const ERROR_SOURCE_ID: usize = 0x0a;
+const RP_PIO_STATUS: usize = 0x0c;
+const RP_PIO_MASK: usize = 0x10;
+const RP_PIO_SEVERITY: usize = 0x14;
+const RP_PIO_SYSERROR: usize = 0x18;
+const RP_PIO_EXCEPTION: usize = 0x1c;
+const RP_PIO_HEADER_LOG: usize = 0x20;
+
+const CAPABILITY_LEN: usize = 0x0c;
+// with the RP extensions, up to the end of the header log
+const RP_PIO_LEN: usize = 0x30;
+const HEADER_LOG_DWORDS: usize = 4;
+
+const TRIGGER_ENABLES: [&str; 4] = ["Disabled", "Fatal", "NonFatal", "Reserved"];
+const TRIGGER_REASONS: [&str; 4] = [
+    "Unmasked Uncorrectable",
+    "ERR_NONFATAL",
+    "ERR_FATAL",
+    "Extension",
+];
+const TRIGGER_REASON_EXTENSIONS: [&str; 4] = ["RP PIO", "Software Trigger", "Reserved", "Reserved"];
+
+const CAPABILITIES_FIELDS: [BitVecFieldDescriptor; 7] = [
+    // DPC Interrupt Message Number
+    BitVecFieldDescriptor {
+        len: 5,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // RP Extensions for DPC
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "RPExt",
+        is_reserved: false,
+    },
+    // Poisoned TLP Egress Blocking Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PoisonedTLP",
+        is_reserved: false,
+    },
+    // DPC Software Triggering Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "SwTrigger",
+        is_reserved: false,
+    },
+    // RP PIO Log Size
+    BitVecFieldDescriptor {
+        len: 4,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // DL_Active ERR_COR Signaling Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "DL_ActiveErr",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 3,
+        name: "Reserved 3",
+        is_reserved: true,
+    },
+];
+
+const CONTROL_FIELDS: [BitVecFieldDescriptor; 8] = [
+    // DPC Trigger Enable
+    BitVecFieldDescriptor {
+        len: 2,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // DPC Completion Control
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Cmpl",
+        is_reserved: false,
+    },
+    // DPC Interrupt Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "INT",
+        is_reserved: false,
+    },
+    // DPC ERR_COR Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ErrCor",
+        is_reserved: false,
+    },
+    // Poisoned TLP Egress Blocking Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PoisonedTLP",
+        is_reserved: false,
+    },
+    // DPC Software Trigger
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "SwTrigger",
+        is_reserved: false,
+    },
+    // DL_Active ERR_COR Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "DL_ActiveErr",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 8,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+];
+
+const STATUS_FIELDS: [BitVecFieldDescriptor; 5] = [
+    // DPC Trigger Status
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Trigger",
+        is_reserved: false,
+    },
+    // DPC Trigger Reason
+    BitVecFieldDescriptor {
+        len: 2,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // DPC Interrupt Status
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "INT",
+        is_reserved: false,
+    },
+    // DPC RP Busy
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "RPBusy",
+        is_reserved: false,
+    },
+    // DPC Trigger Reason Extension, RP PIO First Error Pointer
+    BitVecFieldDescriptor {
+        len: 11,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+];
+
+const RP_PIO_FIELDS: [BitVecFieldDescriptor; 12] = [
+    // Cfg UR Cpl
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CfgUR",
+        is_reserved: false,
+    },
+    // Cfg CA Cpl
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CfgCA",
+        is_reserved: false,
+    },
+    // Cfg Completion Timeout
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "CfgCTO",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 5,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // I/O UR Cpl
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "IOUR",
+        is_reserved: false,
+    },
+    // I/O CA Cpl
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "IOCA",
+        is_reserved: false,
+    },
+    // I/O Completion Timeout
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "IOCTO",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 5,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // Mem UR Cpl
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MemUR",
+        is_reserved: false,
+    },
+    // Mem CA Cpl
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MemCA",
+        is_reserved: false,
+    },
+    // Mem Completion Timeout
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MemCTO",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 13,
+        name: "Reserved 3",
+        is_reserved: true,
+    },
+];
+
+/// The RP PIO registers of a Root Port with the DPC RP extensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RpPioRegisters {
+    pub status: u32,
+    pub mask: u32,
+    pub severity: u32,
+    pub syserror: u32,
+    pub exception: u32,
+    pub header_log: [u32; HEADER_LOG_DWORDS],
+}
+
+/// The Downstream Port Containment extended capability (ID 0x001d).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownstreamPortContainmentCapability {
+    pub offset: u16,
+    pub capability: u16,
+    pub control: u16,
+    pub status: u16,
+    pub error_source_id: u16,
+    // only present with the RP extensions
+    pub rp_pio: Option<RpPioRegisters>,
+}
+
+impl DownstreamPortContainmentCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u16) -> Option<Self> {
+        let base = offset as usize;
+        if base + CAPABILITY_LEN > cf.len() {
+            return None;
+        }
+
+        let capability = cf.word(base + CAPABILITY);
+        let rp_pio = match capability & 0x0020 > 0 {
+            true if base + RP_PIO_LEN <= cf.len() => {
+                let mut header_log = [0; HEADER_LOG_DWORDS];
+                for (index, dword) in header_log.iter_mut().enumerate() {
+                    *dword = cf.dword(base + RP_PIO_HEADER_LOG + index * 4);
+                }
+                Some(RpPioRegisters {
+                    status: cf.dword(base + RP_PIO_STATUS),
+                    mask: cf.dword(base + RP_PIO_MASK),
+                    severity: cf.dword(base + RP_PIO_SEVERITY),
+                    syserror: cf.dword(base + RP_PIO_SYSERROR),
+                    exception: cf.dword(base + RP_PIO_EXCEPTION),
+                    header_log,
+                })
+            }
+            _ => None,
+        };
+
+        Some(Self {
+            offset,
+            capability,
+            control: cf.word(base + CONTROL),
+            status: cf.word(base + STATUS),
+            error_source_id: cf.word(base + ERROR_SOURCE_ID),
+            rp_pio,
+        })
+    }
+
+    pub fn interrupt_message_number(&self) -> u8 {
+        (self.capability & 0x1f) as u8
+    }
+
+    pub fn rp_pio_log_size(&self) -> u8 {
+        ((self.capability >> 8) & 0xf) as u8
+    }
+
+    pub fn trigger_enable(&self) -> &'static str {
+        TRIGGER_ENABLES[(self.control & 0x3) as usize]
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        self.status & 0x1 > 0
+    }
+
+    pub fn trigger_reason(&self) -> &'static str {
+        match (self.status >> 1) & 0x3 {
+            0x3 => TRIGGER_REASON_EXTENSIONS[((self.status >> 5) & 0x3) as usize],
+            reason => TRIGGER_REASONS[reason as usize],
+        }
+    }
+
+    pub fn rp_pio_first_error_pointer(&self) -> u8 {
+        ((self.status >> 8) & 0x1f) as u8
+    }
+}
+
+pub struct DownstreamPortContainmentPrettyPrinter {}
+
+impl Default for DownstreamPortContainmentPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DownstreamPortContainmentPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn print_rp_pio(&self, rp_pio: &RpPioRegisters, lines: &mut Vec<String>) {
+        let registers = [
+            ("RPPIOSta", rp_pio.status),
+            ("RPPIOMsk", rp_pio.mask),
+            ("RPPIOSvrt", rp_pio.severity),
+            ("RPPIOSErr", rp_pio.syserror),
+            ("RPPIOExc", rp_pio.exception),
+        ];
+        for (name, value) in registers {
+            let flags = format_bitvec_flags(value, &RP_PIO_FIELDS);
+            push_register_lines(name, String::new(), flags, lines);
+        }
+
+        let log: Vec<String> = rp_pio
+            .header_log
+            .iter()
+            .map(|dword| format!("{:08x}", dword))
+            .collect();
+        lines.push(format!("{:<9}: {}", "RPPIOLog", log.join(" ")));
+    }
+
+    pub fn print(&self, cap: &DownstreamPortContainmentCapability) -> Vec<String> {
+        let mut lines = vec![];
+
+        let values = format!(
+            "INT Msg #{}, RP PIO Log {}",
+            cap.interrupt_message_number(),
+            cap.rp_pio_log_size()
+        );
+        let flags = format_bitvec_flags(cap.capability as u32, &CAPABILITIES_FIELDS);
+        push_register_lines("DpcCap", values, flags, &mut lines);
+
+        let values = format!("Trigger {}", cap.trigger_enable());
+        let flags = format_bitvec_flags(cap.control as u32, &CONTROL_FIELDS);
+        push_register_lines("DpcCtl", values, flags, &mut lines);
+
+        let values = match cap.is_triggered() {
+            true => format!(
+                "Reason {}, RP PIO ErrPtr 0x{:02x}",
+                cap.trigger_reason(),
+                cap.rp_pio_first_error_pointer()
+            ),
+            false => String::new(),
+        };
+        let flags = format_bitvec_flags(cap.status as u32, &STATUS_FIELDS);
+        push_register_lines("DpcSta", values, flags, &mut lines);
+
+        lines.push(format!("{:<9}: 0x{:04x}", "Source", cap.error_source_id));
+
+        if let Some(rp_pio) = &cap.rp_pio {
+            self.print_rp_pio(rp_pio, &mut lines);
+        }
+
+        lines
+    }
+}
@@ -0,0 +1,237 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::shared::{
+    format_bitvec_flags, push_register_lines, BitVecFieldDescriptor,
+};
+use crate::config_space::ConfigSpace;
+
+// register offsets relative to the start of the capability
+const CAPABILITIES: usize = 0x04;
+const CONTROL_1: usize = 0x08;
+const CONTROL_2: usize = 0x0c;
+
+const CAPABILITY_LEN: usize = 0x10;
+
+// T_POWER_ON scales in us
+const T_POWER_ON_SCALES: [u32; 4] = [2, 10, 100, 0];
+// LTR_L1.2_THRESHOLD scales in ns
+const LTR_THRESHOLD_SCALES: [u64; 8] = [1, 32, 1024, 32_768, 1_048_576, 33_554_432, 0, 0];
+
+const CAPABILITIES_FIELDS: [BitVecFieldDescriptor; 7] = [
+    // PCI-PM L1.2 Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PCI-PM_L1.2",
+        is_reserved: false,
+    },
+    // PCI-PM L1.1 Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PCI-PM_L1.1",
+        is_reserved: false,
+    },
+    // ASPM L1.2 Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ASPM_L1.2",
+        is_reserved: false,
+    },
+    // ASPM L1.1 Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ASPM_L1.1",
+        is_reserved: false,
+    },
+    // L1 PM Substates Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "L1_PM_Substates",
+        is_reserved: false,
+    },
+    // Link Activation Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "LinkActivation",
+        is_reserved: false,
+    },
+    // Port Common_Mode_Restore_Time, Port T_POWER_ON
+    BitVecFieldDescriptor {
+        len: 26,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+const CONTROL_1_FIELDS: [BitVecFieldDescriptor; 7] = [
+    // PCI-PM L1.2 Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PCI-PM_L1.2",
+        is_reserved: false,
+    },
+    // PCI-PM L1.1 Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PCI-PM_L1.1",
+        is_reserved: false,
+    },
+    // ASPM L1.2 Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ASPM_L1.2",
+        is_reserved: false,
+    },
+    // ASPM L1.1 Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ASPM_L1.1",
+        is_reserved: false,
+    },
+    // Link Activation Interrupt Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "LinkActIntEn",
+        is_reserved: false,
+    },
+    // Link Activation Control
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "LinkActCtl",
+        is_reserved: false,
+    },
+    // Common_Mode_Restore_Time, LTR_L1.2_THRESHOLD
+    BitVecFieldDescriptor {
+        len: 26,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+/// The L1 PM Substates extended capability (ID 0x001e).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct L1PmSubstatesCapability {
+    pub offset: u16,
+    pub capabilities: u32,
+    pub control_1: u32,
+    pub control_2: u32,
+}
+
+impl L1PmSubstatesCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u16) -> Option<Self> {
+        let base = offset as usize;
+        if base + CAPABILITY_LEN > cf.len() {
+            return None;
+        }
+
+        Some(Self {
+            offset,
+            capabilities: cf.dword(base + CAPABILITIES),
+            control_1: cf.dword(base + CONTROL_1),
+            control_2: cf.dword(base + CONTROL_2),
+        })
+    }
+
+    // PCI-PM L1.2, PCI-PM L1.1, ASPM L1.2 and ASPM L1.1 in bits 3:0
+    pub fn substates_support(&self) -> u8 {
+        (self.capabilities & 0xf) as u8
+    }
+
+    pub fn substates_control(&self) -> u8 {
+        (self.control_1 & 0xf) as u8
+    }
+
+    // in us
+    pub fn port_common_mode_restore_time(&self) -> u32 {
+        (self.capabilities >> 8) & 0xff
+    }
+
+    // in us, None for the reserved scale
+    pub fn port_t_power_on(&self) -> Option<u32> {
+        t_power_on(
+            self.capabilities >> 16 & 0x3,
+            self.capabilities >> 19 & 0x1f,
+        )
+    }
+
+    // in us
+    pub fn t_common_mode(&self) -> u32 {
+        (self.control_1 >> 8) & 0xff
+    }
+
+    // in ns, None for a reserved scale
+    pub fn ltr_threshold(&self) -> Option<u64> {
+        let value = ((self.control_1 >> 16) & 0x3ff) as u64;
+        match LTR_THRESHOLD_SCALES[(self.control_1 >> 29) as usize] {
+            0 => None,
+            scale => Some(value * scale),
+        }
+    }
+
+    // in us, None for the reserved scale
+    pub fn t_power_on(&self) -> Option<u32> {
+        t_power_on(self.control_2 & 0x3, self.control_2 >> 3 & 0x1f)
+    }
+}
+
+fn t_power_on(scale: u32, value: u32) -> Option<u32> {
+    match T_POWER_ON_SCALES[scale as usize] {
+        0 => None,
+        scale => Some(value * scale),
+    }
+}
+
+fn format_time(value: Option<u32>) -> String {
+    match value {
+        Some(us) => format!("{}us", us),
+        None => String::from("reserved"),
+    }
+}
+
+pub struct L1PmSubstatesPrettyPrinter {}
+
+impl Default for L1PmSubstatesPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl L1PmSubstatesPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, cap: &L1PmSubstatesCapability) -> Vec<String> {
+        let mut lines = vec![];
+
+        let values = format!(
+            "PortCommonModeRestoreTime={}us PortTPowerOnTime={}",
+            cap.port_common_mode_restore_time(),
+            format_time(cap.port_t_power_on())
+        );
+        let flags = format_bitvec_flags(cap.capabilities, &CAPABILITIES_FIELDS);
+        push_register_lines("L1SubCap", values, flags, &mut lines);
+
+        let threshold = match cap.ltr_threshold() {
+            Some(ns) => format!("{}ns", ns),
+            None => String::from("reserved"),
+        };
+        let values = format!(
+            "T_CommonMode={}us LTR1.2_Threshold={}",
+            cap.t_common_mode(),
+            threshold
+        );
+        let flags = format_bitvec_flags(cap.control_1, &CONTROL_1_FIELDS);
+        push_register_lines("L1SubCtl1", values, flags, &mut lines);
+
+        lines.push(format!(
+            "{:<9}: T_PwrOn={}",
+            "L1SubCtl2",
+            format_time(cap.t_power_on())
+        ));
+
+        lines
+    }
+}
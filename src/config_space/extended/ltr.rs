@@ -0,0 +1,85 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::ConfigSpace;
+
+// register offsets relative to the start of the capability
+const MAX_SNOOP_LATENCY: usize = 0x04;
+const MAX_NO_SNOOP_LATENCY: usize = 0x06;
+
+const CAPABILITY_LEN: usize = 0x08;
+
+// latency scales in ns
+const LATENCY_SCALES: [u64; 8] = [1, 32, 1024, 32_768, 1_048_576, 33_554_432, 0, 0];
+
+/// The Latency Tolerance Reporting extended capability (ID 0x0018).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencyToleranceReportingCapability {
+    pub offset: u16,
+    pub max_snoop_latency: u16,
+    pub max_no_snoop_latency: u16,
+}
+
+impl LatencyToleranceReportingCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u16) -> Option<Self> {
+        let base = offset as usize;
+        if base + CAPABILITY_LEN > cf.len() {
+            return None;
+        }
+
+        Some(Self {
+            offset,
+            max_snoop_latency: cf.word(base + MAX_SNOOP_LATENCY),
+            max_no_snoop_latency: cf.word(base + MAX_NO_SNOOP_LATENCY),
+        })
+    }
+
+    // in ns, None for a reserved scale
+    pub fn max_snoop(&self) -> Option<u64> {
+        latency(self.max_snoop_latency)
+    }
+
+    // in ns, None for a reserved scale
+    pub fn max_no_snoop(&self) -> Option<u64> {
+        latency(self.max_no_snoop_latency)
+    }
+}
+
+// value in bits 9:0, scale in bits 12:10
+fn latency(register: u16) -> Option<u64> {
+    let value = (register & 0x3ff) as u64;
+    match LATENCY_SCALES[((register >> 10) & 0x7) as usize] {
+        0 => None,
+        scale => Some(value * scale),
+    }
+}
+
+fn format_latency(value: Option<u64>) -> String {
+    match value {
+        Some(ns) => format!("{}ns", ns),
+        None => String::from("reserved"),
+    }
+}
+
+pub struct LatencyToleranceReportingPrettyPrinter {}
+
+impl Default for LatencyToleranceReportingPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LatencyToleranceReportingPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, cap: &LatencyToleranceReportingCapability) -> Vec<String> {
+        vec![
+            format!("{:<9}: {}", "MaxSnoop", format_latency(cap.max_snoop())),
+            format!("{:<9}: {}", "MaxNoSnp", format_latency(cap.max_no_snoop())),
+        ]
+    }
+}
@@ -0,0 +1,380 @@
+mod acs;
+mod aer;
+mod ats;
+mod dpc;
+mod l1pm;
+mod ltr;
+mod pasid;
+mod pri;
+mod ptm;
+mod rebar;
+mod sriov;
+
+use alloc::vec;
+use alloc::{string::String, vec::Vec};
+
+use crate::config_space::vendor_specific::{
+    VendorCapability, VendorCapabilityPrettyPrinter, VendorCapabilityRegistry,
+};
+use crate::config_space::ConfigSpace;
+
+pub use acs::{AccessControlServicesCapability, AccessControlServicesPrettyPrinter};
+pub use aer::{AdvancedErrorReportingCapability, AdvancedErrorReportingPrettyPrinter};
+pub use ats::{AddressTranslationServicesCapability, AddressTranslationServicesPrettyPrinter};
+pub use dpc::{
+    DownstreamPortContainmentCapability, DownstreamPortContainmentPrettyPrinter, RpPioRegisters,
+};
+pub use l1pm::{L1PmSubstatesCapability, L1PmSubstatesPrettyPrinter};
+pub use ltr::{LatencyToleranceReportingCapability, LatencyToleranceReportingPrettyPrinter};
+pub use pasid::{PasidCapability, PasidPrettyPrinter};
+pub use pri::{PageRequestInterfaceCapability, PageRequestInterfacePrettyPrinter};
+pub use ptm::{PrecisionTimeMeasurementCapability, PrecisionTimeMeasurementPrettyPrinter};
+pub use rebar::{
+    ResizableBar, ResizableBarCapability, ResizableBarPrettyPrinter, ResizableBarReport,
+};
+pub use sriov::{SriovCapability, SriovPrettyPrinter};
+
+pub const EXT_CAP_ID_AER: u16 = 0x0001;
+pub const EXT_CAP_ID_VSEC: u16 = 0x000b;
+pub const EXT_CAP_ID_ACS: u16 = 0x000d;
+pub const EXT_CAP_ID_ATS: u16 = 0x000f;
+pub const EXT_CAP_ID_SRIOV: u16 = 0x0010;
+pub const EXT_CAP_ID_PRI: u16 = 0x0013;
+pub const EXT_CAP_ID_RESIZABLE_BAR: u16 = 0x0015;
+pub const EXT_CAP_ID_LTR: u16 = 0x0018;
+pub const EXT_CAP_ID_PASID: u16 = 0x001b;
+pub const EXT_CAP_ID_DPC: u16 = 0x001d;
+pub const EXT_CAP_ID_L1_PM_SUBSTATES: u16 = 0x001e;
+pub const EXT_CAP_ID_PTM: u16 = 0x001f;
+pub const EXT_CAP_ID_DVSEC: u16 = 0x0023;
+
+// extended capabilities live after the 256 bytes of conventional config space
+pub const EXTENDED_CAPABILITIES_START: usize = 0x100;
+const EXTENDED_CONFIG_SPACE_LEN: usize = 0x1000;
+
+const EXTENDED_CAPABILITY_NAMES: [(u16, &str); 52] = [
+    (0x0001, "Advanced Error Reporting"),
+    (0x0002, "Virtual Channel"),
+    (0x0003, "Device Serial Number"),
+    (0x0004, "Power Budgeting"),
+    (0x0005, "Root Complex Link Declaration"),
+    (0x0006, "Root Complex Internal Link Control"),
+    (0x0007, "Root Complex Event Collector Endpoint Association"),
+    (0x0008, "Multi-Function Virtual Channel"),
+    (0x0009, "Virtual Channel"),
+    (0x000a, "Root Complex Register Block"),
+    (0x000b, "Vendor-Specific Extended"),
+    (0x000c, "Configuration Access Correlation"),
+    (0x000d, "Access Control Services"),
+    (0x000e, "Alternative Routing-ID Interpretation"),
+    (0x000f, "Address Translation Services"),
+    (0x0010, "Single Root I/O Virtualization"),
+    (0x0011, "Multi-Root I/O Virtualization"),
+    (0x0012, "Multicast"),
+    (0x0013, "Page Request Interface"),
+    (0x0014, "Reserved for AMD"),
+    (0x0015, "Resizable BAR"),
+    (0x0016, "Dynamic Power Allocation"),
+    (0x0017, "TPH Requester"),
+    (0x0018, "Latency Tolerance Reporting"),
+    (0x0019, "Secondary PCI Express"),
+    (0x001a, "Protocol Multiplexing"),
+    (0x001b, "Process Address Space ID"),
+    (0x001c, "LN Requester"),
+    (0x001d, "Downstream Port Containment"),
+    (0x001e, "L1 PM Substates"),
+    (0x001f, "Precision Time Measurement"),
+    (0x0020, "PCI Express over M-PHY"),
+    (0x0021, "FRS Queueing"),
+    (0x0022, "Readiness Time Reporting"),
+    (0x0023, "Designated Vendor-Specific"),
+    (0x0024, "VF Resizable BAR"),
+    (0x0025, "Data Link Feature"),
+    (0x0026, "Physical Layer 16.0 GT/s"),
+    (0x0027, "Lane Margining at the Receiver"),
+    (0x0028, "Hierarchy ID"),
+    (0x0029, "Native PCIe Enclosure Management"),
+    (0x002a, "Physical Layer 32.0 GT/s"),
+    (0x002b, "Alternate Protocol"),
+    (0x002c, "System Firmware Intermediary"),
+    (0x002d, "Shadow Functions"),
+    (0x002e, "Data Object Exchange"),
+    (0x002f, "Device 3"),
+    (0x0030, "Integrity and Data Encryption"),
+    (0x0031, "Physical Layer 64.0 GT/s"),
+    (0x0032, "Flit Logging"),
+    (0x0033, "Flit Performance Measurement"),
+    (0x0034, "Flit Error Injection"),
+];
+
+pub fn extended_capability_name(id: u16) -> Option<&'static str> {
+    EXTENDED_CAPABILITY_NAMES
+        .iter()
+        .find(|(cap_id, _)| *cap_id == id)
+        .map(|(_, name)| *name)
+}
+
+/// An entry in the PCI Express extended capability list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtendedCapability {
+    pub offset: u16,
+    pub id: u16,
+    pub version: u8,
+    pub next: u16,
+}
+
+/// Reasons the extended capability list can't be followed any further. Each
+/// carries the offending pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtendedCapabilityError {
+    // the pointer leads back to a capability already visited
+    Loop(u16),
+    // the pointer isn't dword aligned
+    Misaligned(u16),
+    // the pointer is below 0x100 or past the readable config space
+    OutOfBounds(u16),
+}
+
+/// Walks the extended capability list starting at 0x100. Yields nothing when
+/// the extended config space isn't readable or holds no capabilities. After
+/// an error is yielded the iterator is exhausted.
+pub struct ExtendedCapabilityIter<'a> {
+    cf: &'a ConfigSpace,
+    next: u16,
+    // one bit per dword in 0x000-0xfff
+    visited: [u64; EXTENDED_CONFIG_SPACE_LEN / 4 / 64],
+}
+
+impl<'a> ExtendedCapabilityIter<'a> {
+    pub fn new(cf: &'a ConfigSpace) -> Self {
+        let start = EXTENDED_CAPABILITIES_START;
+
+        // an empty list reads as all zeros, a missing extended space as all ones
+        let next = match cf.len() >= start + 4 {
+            true => match cf.dword(start) {
+                0x0000_0000 | 0xffff_ffff => 0,
+                _ => start as u16,
+            },
+            false => 0,
+        };
+
+        Self {
+            cf,
+            next,
+            visited: [0; EXTENDED_CONFIG_SPACE_LEN / 4 / 64],
+        }
+    }
+
+    fn step(&mut self, offset: u16) -> Result<ExtendedCapability, ExtendedCapabilityError> {
+        if offset & 0x3 != 0 {
+            return Err(ExtendedCapabilityError::Misaligned(offset));
+        }
+
+        let position = offset as usize;
+        if position < EXTENDED_CAPABILITIES_START || position + 4 > self.cf.len() {
+            return Err(ExtendedCapabilityError::OutOfBounds(offset));
+        }
+
+        let dword = position / 4;
+        let bit = 1u64 << (dword % 64);
+        if self.visited[dword / 64] & bit > 0 {
+            return Err(ExtendedCapabilityError::Loop(offset));
+        }
+        self.visited[dword / 64] |= bit;
+
+        let header = self.cf.dword(position);
+        Ok(ExtendedCapability {
+            offset,
+            id: (header & 0xffff) as u16,
+            version: ((header >> 16) & 0xf) as u8,
+            next: (header >> 20) as u16,
+        })
+    }
+}
+
+impl<'a> Iterator for ExtendedCapabilityIter<'a> {
+    type Item = Result<ExtendedCapability, ExtendedCapabilityError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == 0 {
+            return None;
+        }
+
+        let result = self.step(self.next);
+        self.next = match result {
+            Ok(cap) => cap.next,
+            Err(_) => 0,
+        };
+
+        Some(result)
+    }
+}
+
+/// Decodes the body of the extended capabilities it knows about. Others
+/// produce no lines, leaving just the name.
+pub struct ExtendedCapabilityPrettyPrinter {}
+
+impl Default for ExtendedCapabilityPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExtendedCapabilityPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(
+        &self,
+        cf: &ConfigSpace,
+        cap: &ExtendedCapability,
+        vendor_decoders: &VendorCapabilityRegistry,
+    ) -> Vec<String> {
+        match cap.id {
+            EXT_CAP_ID_AER => {
+                let has_root = matches!(cf.pci_express(), Some(pcie) if pcie.has_root());
+                match AdvancedErrorReportingCapability::read(cf, cap.offset, has_root) {
+                    Some(aer) => AdvancedErrorReportingPrettyPrinter::new().print(&aer),
+                    None => vec![String::from("[truncated]")],
+                }
+            }
+            EXT_CAP_ID_VSEC => {
+                let vendor = VendorCapability::read_vsec(cf, cap.offset);
+                VendorCapabilityPrettyPrinter::new().print(vendor, vendor_decoders)
+            }
+            EXT_CAP_ID_DVSEC => {
+                let vendor = VendorCapability::read_dvsec(cf, cap.offset);
+                VendorCapabilityPrettyPrinter::new().print(vendor, vendor_decoders)
+            }
+            EXT_CAP_ID_ACS => match AccessControlServicesCapability::read(cf, cap.offset) {
+                Some(acs) => AccessControlServicesPrettyPrinter::new().print(&acs),
+                None => vec![String::from("[truncated]")],
+            },
+            EXT_CAP_ID_ATS => match AddressTranslationServicesCapability::read(cf, cap.offset) {
+                Some(ats) => AddressTranslationServicesPrettyPrinter::new().print(&ats),
+                None => vec![String::from("[truncated]")],
+            },
+            EXT_CAP_ID_SRIOV => match SriovCapability::read(cf, cap.offset) {
+                Some(sriov) => SriovPrettyPrinter::new().print(&sriov),
+                None => vec![String::from("[truncated]")],
+            },
+            EXT_CAP_ID_PRI => match PageRequestInterfaceCapability::read(cf, cap.offset) {
+                Some(pri) => PageRequestInterfacePrettyPrinter::new().print(&pri),
+                None => vec![String::from("[truncated]")],
+            },
+            EXT_CAP_ID_RESIZABLE_BAR => match ResizableBarCapability::read(cf, cap.offset) {
+                Some(rebar) => ResizableBarPrettyPrinter::new().print(&rebar),
+                None => vec![String::from("[truncated]")],
+            },
+            EXT_CAP_ID_LTR => match LatencyToleranceReportingCapability::read(cf, cap.offset) {
+                Some(ltr) => LatencyToleranceReportingPrettyPrinter::new().print(&ltr),
+                None => vec![String::from("[truncated]")],
+            },
+            EXT_CAP_ID_PASID => match PasidCapability::read(cf, cap.offset) {
+                Some(pasid) => PasidPrettyPrinter::new().print(&pasid),
+                None => vec![String::from("[truncated]")],
+            },
+            EXT_CAP_ID_DPC => match DownstreamPortContainmentCapability::read(cf, cap.offset) {
+                Some(dpc) => DownstreamPortContainmentPrettyPrinter::new().print(&dpc),
+                None => vec![String::from("[truncated]")],
+            },
+            EXT_CAP_ID_L1_PM_SUBSTATES => match L1PmSubstatesCapability::read(cf, cap.offset) {
+                Some(l1pm) => L1PmSubstatesPrettyPrinter::new().print(&l1pm),
+                None => vec![String::from("[truncated]")],
+            },
+            EXT_CAP_ID_PTM => match PrecisionTimeMeasurementCapability::read(cf, cap.offset) {
+                Some(ptm) => PrecisionTimeMeasurementPrettyPrinter::new().print(&ptm),
+                None => vec![String::from("[truncated]")],
+            },
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AER: u16 = 0x0001;
+    const ACS: u16 = 0x000d;
+
+    // extended capabilities of (offset, id, next) entries, version 1
+    fn config_space(len: usize, caps: &[(u16, u16, u16)]) -> ConfigSpace {
+        let mut bytes = vec![0; len];
+        for (offset, id, next) in caps {
+            let header = *id as u32 | 1 << 16 | (*next as u32) << 20;
+            let offset = *offset as usize;
+            bytes[offset..offset + 4].copy_from_slice(&header.to_le_bytes());
+        }
+        ConfigSpace::from(bytes)
+    }
+
+    fn walk(cf: &ConfigSpace) -> Vec<Result<u16, ExtendedCapabilityError>> {
+        cf.extended_capabilities()
+            .map(|result| result.map(|cap| cap.offset))
+            .collect()
+    }
+
+    #[test]
+    fn walks_the_list() {
+        let cf = config_space(0x1000, &[(0x100, AER, 0x200), (0x200, ACS, 0)]);
+        assert_eq!(walk(&cf), vec![Ok(0x100), Ok(0x200)]);
+
+        let caps: Vec<ExtendedCapability> =
+            cf.extended_capabilities().filter_map(Result::ok).collect();
+        assert_eq!(
+            caps[1],
+            ExtendedCapability {
+                offset: 0x200,
+                id: ACS,
+                version: 1,
+                next: 0
+            }
+        );
+    }
+
+    #[test]
+    fn yields_nothing_without_extended_space() {
+        assert_eq!(walk(&config_space(0x100, &[])), vec![]);
+        assert_eq!(walk(&config_space(0x1000, &[])), vec![]);
+        assert_eq!(walk(&ConfigSpace::from(vec![0xff; 0x1000])), vec![]);
+    }
+
+    #[test]
+    fn stops_at_a_loop() {
+        let cf = config_space(0x1000, &[(0x100, AER, 0x200), (0x200, ACS, 0x100)]);
+        assert_eq!(
+            walk(&cf),
+            vec![
+                Ok(0x100),
+                Ok(0x200),
+                Err(ExtendedCapabilityError::Loop(0x100))
+            ]
+        );
+    }
+
+    #[test]
+    fn stops_at_a_misaligned_pointer() {
+        let cf = config_space(0x1000, &[(0x100, AER, 0x202)]);
+        assert_eq!(
+            walk(&cf),
+            vec![Ok(0x100), Err(ExtendedCapabilityError::Misaligned(0x202))]
+        );
+    }
+
+    #[test]
+    fn stops_at_a_pointer_out_of_bounds() {
+        let cf = config_space(0x1000, &[(0x100, AER, 0x040)]);
+        assert_eq!(
+            walk(&cf),
+            vec![Ok(0x100), Err(ExtendedCapabilityError::OutOfBounds(0x040))]
+        );
+
+        let cf = config_space(0x200, &[(0x100, AER, 0x200)]);
+        assert_eq!(
+            walk(&cf),
+            vec![Ok(0x100), Err(ExtendedCapabilityError::OutOfBounds(0x200))]
+        );
+    }
+}
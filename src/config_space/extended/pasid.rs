@@ -0,0 +1,122 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::shared::{
+    format_bitvec_flags, push_register_lines, BitVecFieldDescriptor,
+};
+use crate::config_space::ConfigSpace;
+
+// register offsets relative to the start of the capability
+const CAPABILITY: usize = 0x04;
+const CONTROL: usize = 0x06;
+
+const CAPABILITY_LEN: usize = 0x08;
+
+const CAPABILITIES_FIELDS: [BitVecFieldDescriptor; 4] = [
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Execute Permission Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Exec",
+        is_reserved: false,
+    },
+    // Privileged Mode Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Priv",
+        is_reserved: false,
+    },
+    // Max PASID Width
+    BitVecFieldDescriptor {
+        len: 13,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+];
+
+const CONTROL_FIELDS: [BitVecFieldDescriptor; 4] = [
+    // PASID Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Enable",
+        is_reserved: false,
+    },
+    // Execute Permission Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Exec",
+        is_reserved: false,
+    },
+    // Privileged Mode Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Priv",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 13,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+/// The Process Address Space ID extended capability (ID 0x001b).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasidCapability {
+    pub offset: u16,
+    pub capability: u16,
+    pub control: u16,
+}
+
+impl PasidCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u16) -> Option<Self> {
+        let base = offset as usize;
+        if base + CAPABILITY_LEN > cf.len() {
+            return None;
+        }
+
+        Some(Self {
+            offset,
+            capability: cf.word(base + CAPABILITY),
+            control: cf.word(base + CONTROL),
+        })
+    }
+
+    // the number of PASID bits supported, up to 20
+    pub fn max_width(&self) -> u8 {
+        ((self.capability >> 8) & 0x1f) as u8
+    }
+}
+
+pub struct PasidPrettyPrinter {}
+
+impl Default for PasidPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PasidPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, cap: &PasidCapability) -> Vec<String> {
+        let mut lines = vec![];
+
+        let values = format!("Max PASID Width {}", cap.max_width());
+        let flags = format_bitvec_flags(cap.capability as u32, &CAPABILITIES_FIELDS);
+        push_register_lines("PASIDCap", values, flags, &mut lines);
+
+        let flags = format_bitvec_flags(cap.control as u32, &CONTROL_FIELDS);
+        push_register_lines("PASIDCtl", String::new(), flags, &mut lines);
+
+        lines
+    }
+}
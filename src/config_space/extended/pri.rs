@@ -0,0 +1,132 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::shared::{
+    format_bitvec_flags, push_register_lines, BitVecFieldDescriptor,
+};
+use crate::config_space::ConfigSpace;
+
+// register offsets relative to the start of the capability
+const CONTROL: usize = 0x04;
+const STATUS: usize = 0x06;
+const OUTSTANDING_CAPACITY: usize = 0x08;
+const OUTSTANDING_ALLOCATION: usize = 0x0c;
+
+const CAPABILITY_LEN: usize = 0x10;
+
+const CONTROL_FIELDS: [BitVecFieldDescriptor; 3] = [
+    // PRI Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Enable",
+        is_reserved: false,
+    },
+    // PRI Reset
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Reset",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 14,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+const STATUS_FIELDS: [BitVecFieldDescriptor; 6] = [
+    // Response Failure
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "RF",
+        is_reserved: false,
+    },
+    // Unexpected Page Request Group Index
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "UPRGI",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 6,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // Stopped
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Stopped",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 6,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // PRG Response PASID Required
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PASID",
+        is_reserved: false,
+    },
+];
+
+/// The Page Request Interface extended capability (ID 0x0013).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequestInterfaceCapability {
+    pub offset: u16,
+    pub control: u16,
+    pub status: u16,
+    pub outstanding_capacity: u32,
+    pub outstanding_allocation: u32,
+}
+
+impl PageRequestInterfaceCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u16) -> Option<Self> {
+        let base = offset as usize;
+        if base + CAPABILITY_LEN > cf.len() {
+            return None;
+        }
+
+        Some(Self {
+            offset,
+            control: cf.word(base + CONTROL),
+            status: cf.word(base + STATUS),
+            outstanding_capacity: cf.dword(base + OUTSTANDING_CAPACITY),
+            outstanding_allocation: cf.dword(base + OUTSTANDING_ALLOCATION),
+        })
+    }
+}
+
+pub struct PageRequestInterfacePrettyPrinter {}
+
+impl Default for PageRequestInterfacePrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PageRequestInterfacePrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, cap: &PageRequestInterfaceCapability) -> Vec<String> {
+        let mut lines = vec![];
+
+        let flags = format_bitvec_flags(cap.control as u32, &CONTROL_FIELDS);
+        push_register_lines("PRICtl", String::new(), flags, &mut lines);
+
+        let flags = format_bitvec_flags(cap.status as u32, &STATUS_FIELDS);
+        push_register_lines("PRISta", String::new(), flags, &mut lines);
+
+        lines.push(format!(
+            "{:<9}: Outstanding Capacity {}, Allocation {}",
+            "PRIPages", cap.outstanding_capacity, cap.outstanding_allocation
+        ));
+
+        lines
+    }
+}
@@ -0,0 +1,149 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::shared::{
+    format_bitvec_flags, push_register_lines, BitVecFieldDescriptor,
+};
+use crate::config_space::ConfigSpace;
+
+// register offsets relative to the start of the capability
+const CAPABILITY: usize = 0x04;
+const CONTROL: usize = 0x08;
+
+const CAPABILITY_LEN: usize = 0x0c;
+
+const CAPABILITIES_FIELDS: [BitVecFieldDescriptor; 6] = [
+    // PTM Requester Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Requester",
+        is_reserved: false,
+    },
+    // PTM Responder Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Responder",
+        is_reserved: false,
+    },
+    // PTM Root Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Root",
+        is_reserved: false,
+    },
+    // ePTM Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ePTM",
+        is_reserved: false,
+    },
+    // PTM Propagation Delay Adaptation Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PropDelay",
+        is_reserved: false,
+    },
+    // Local Clock Granularity
+    BitVecFieldDescriptor {
+        len: 27,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+const CONTROL_FIELDS: [BitVecFieldDescriptor; 3] = [
+    // PTM Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Enabled",
+        is_reserved: false,
+    },
+    // Root Select
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "RootSelected",
+        is_reserved: false,
+    },
+    // Effective Granularity
+    BitVecFieldDescriptor {
+        len: 30,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+/// The Precision Time Measurement extended capability (ID 0x001f).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrecisionTimeMeasurementCapability {
+    pub offset: u16,
+    pub capability: u32,
+    pub control: u32,
+}
+
+impl PrecisionTimeMeasurementCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u16) -> Option<Self> {
+        let base = offset as usize;
+        if base + CAPABILITY_LEN > cf.len() {
+            return None;
+        }
+
+        Some(Self {
+            offset,
+            capability: cf.dword(base + CAPABILITY),
+            control: cf.dword(base + CONTROL),
+        })
+    }
+
+    pub fn local_clock_granularity(&self) -> u8 {
+        ((self.capability >> 8) & 0xff) as u8
+    }
+
+    pub fn effective_granularity(&self) -> u8 {
+        ((self.control >> 8) & 0xff) as u8
+    }
+}
+
+// 0 means unknown or not implemented, 0xff more than 254ns
+fn format_granularity(value: u8) -> String {
+    match value {
+        0x00 => String::from("Unknown"),
+        0xff => String::from(">254ns"),
+        ns => format!("{}ns", ns),
+    }
+}
+
+pub struct PrecisionTimeMeasurementPrettyPrinter {}
+
+impl Default for PrecisionTimeMeasurementPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrecisionTimeMeasurementPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, cap: &PrecisionTimeMeasurementCapability) -> Vec<String> {
+        let mut lines = vec![];
+
+        let values = format!(
+            "Granularity {}",
+            format_granularity(cap.local_clock_granularity())
+        );
+        let flags = format_bitvec_flags(cap.capability, &CAPABILITIES_FIELDS);
+        push_register_lines("PTMCap", values, flags, &mut lines);
+
+        let values = format!(
+            "Granularity {}",
+            format_granularity(cap.effective_granularity())
+        );
+        let flags = format_bitvec_flags(cap.control, &CONTROL_FIELDS);
+        push_register_lines("PTMCtl", values, flags, &mut lines);
+
+        lines
+    }
+}
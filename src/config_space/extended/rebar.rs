@@ -0,0 +1,332 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::bridge_window::{BridgeWindow, BridgeWindows};
+use crate::config_space::resource::ResourceSizes;
+use crate::config_space::{format_size, ConfigSpace};
+
+// register offsets relative to the start of the capability, each BAR has a
+// capability and a control register
+const ENTRIES: usize = 0x04;
+const ENTRY_CAPABILITY: usize = 0x00;
+const ENTRY_CONTROL: usize = 0x04;
+const ENTRY_LEN: usize = 0x08;
+
+const MAX_ENTRIES: usize = 6;
+
+// offsets into the header
+const CLASS_CODE_OFFSET: usize = 0x0b;
+const CLASS_DISPLAY_CONTROLLER: u8 = 0x03;
+
+// sizes are encoded as powers of two starting at 1M
+const SIZE_SHIFT: u32 = 20;
+// bits 31:4 of the capability register cover 1M to 128T, bits 31:16 of the
+// control register continue from 256T
+const CAPABILITY_SIZES: u32 = 28;
+
+/// One BAR of a Resizable BAR capability. `supported` has bit n set when a
+/// size of `1M << n` is supported. `current_size` is `None` for the reserved
+/// encodings, which are too large to fit in a u64.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResizableBar {
+    pub bar_index: u8,
+    pub supported: u64,
+    pub current_size: Option<u64>,
+}
+
+impl ResizableBar {
+    pub fn supported_sizes(&self) -> Vec<u64> {
+        (0..64 - SIZE_SHIFT)
+            .filter(|bit| self.supported & (1 << bit) > 0)
+            .map(|bit| 1 << (bit + SIZE_SHIFT))
+            .collect()
+    }
+
+    pub fn max_size(&self) -> Option<u64> {
+        self.supported_sizes().last().copied()
+    }
+
+    pub fn can_grow(&self) -> bool {
+        match (self.max_size(), self.current_size) {
+            (Some(max), Some(current)) => max > current,
+            _ => false,
+        }
+    }
+}
+
+/// The Resizable BAR extended capability (ID 0x0015).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResizableBarCapability {
+    pub offset: u16,
+    pub bars: Vec<ResizableBar>,
+}
+
+impl ResizableBarCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u16) -> Option<Self> {
+        let base = offset as usize + ENTRIES;
+        if base + ENTRY_LEN > cf.len() {
+            return None;
+        }
+
+        // the number of entries is only given in the first control register
+        let count = ((cf.dword(base + ENTRY_CONTROL) >> 5) & 0x7) as usize;
+        let count = count.clamp(1, MAX_ENTRIES);
+        if base + count * ENTRY_LEN > cf.len() {
+            return None;
+        }
+
+        let bars = (0..count)
+            .map(|index| {
+                let entry = base + index * ENTRY_LEN;
+                let capability = cf.dword(entry + ENTRY_CAPABILITY);
+                let control = cf.dword(entry + ENTRY_CONTROL);
+
+                let supported =
+                    ((capability >> 4) as u64) | ((control >> 16) as u64) << CAPABILITY_SIZES;
+                ResizableBar {
+                    bar_index: (control & 0x7) as u8,
+                    supported,
+                    current_size: 1u64.checked_shl(((control >> 8) & 0x3f) + SIZE_SHIFT),
+                }
+            })
+            .collect();
+
+        Some(Self { offset, bars })
+    }
+}
+
+/// A resizable BAR set against what the system gave it: the size of the
+/// assigned resource and the prefetchable window of the bridge above.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResizableBarReport {
+    pub bar: ResizableBar,
+    pub is_gpu: bool,
+    // as assigned by the OS, if known
+    pub resource_size: Option<u64>,
+    pub upstream_window: Option<BridgeWindow>,
+}
+
+impl ResizableBarReport {
+    /// Builds a report for each BAR of `rebar`. `upstream` is the config
+    /// space of the bridge directly above the device, if there is one.
+    pub fn analyze(
+        cf: &ConfigSpace,
+        rebar: &ResizableBarCapability,
+        sizes: &ResourceSizes,
+        upstream: Option<&ConfigSpace>,
+    ) -> Vec<Self> {
+        let is_gpu = cf.byte(CLASS_CODE_OFFSET) == CLASS_DISPLAY_CONTROLLER;
+        let upstream_window = upstream
+            .map(|bridge| BridgeWindows::from(bridge).prefetchable)
+            .filter(|window| window.is_enabled());
+
+        rebar
+            .bars
+            .iter()
+            .map(|bar| Self {
+                bar: *bar,
+                is_gpu,
+                resource_size: sizes.get(bar.bar_index as usize),
+                upstream_window,
+            })
+            .collect()
+    }
+
+    /// Whether the upstream window has room for a naturally aligned BAR of
+    /// the largest supported size. Other devices sharing the window are not
+    /// accounted for.
+    pub fn window_fits_max(&self) -> Option<bool> {
+        let window = self.upstream_window?;
+        let max = self.bar.max_size()?;
+
+        let aligned = window.base.checked_add(max - 1)? / max * max;
+        Some(matches!(aligned.checked_add(max - 1), Some(end) if end <= window.limit))
+    }
+
+    /// A GPU that supports a larger BAR than the one it has now.
+    pub fn is_undersized_gpu(&self) -> bool {
+        self.is_gpu && self.bar.can_grow()
+    }
+}
+
+pub struct ResizableBarPrettyPrinter {}
+
+impl Default for ResizableBarPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResizableBarPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn format_current(&self, bar: &ResizableBar) -> String {
+        match bar.current_size {
+            Some(size) => format_size(size),
+            None => String::from("reserved"),
+        }
+    }
+
+    fn format_sizes(&self, sizes: &[u64]) -> String {
+        let names: Vec<String> = sizes.iter().map(|size| format_size(*size)).collect();
+        names.join(" ")
+    }
+
+    pub fn print(&self, cap: &ResizableBarCapability) -> Vec<String> {
+        cap.bars
+            .iter()
+            .map(|bar| {
+                format!(
+                    "BAR {}: current {}, supported {}",
+                    bar.bar_index,
+                    self.format_current(bar),
+                    self.format_sizes(&bar.supported_sizes())
+                )
+            })
+            .collect()
+    }
+
+    pub fn print_report(&self, report: &ResizableBarReport) -> Vec<String> {
+        let bar = &report.bar;
+
+        let resource = match report.resource_size {
+            Some(size) => format_size(size),
+            None => String::from("unknown"),
+        };
+        let max = match bar.max_size() {
+            Some(size) => format_size(size),
+            None => String::from("none"),
+        };
+        let mut lines = vec![format!(
+            "BAR{} resource {}, resizable {} of max {}",
+            bar.bar_index,
+            resource,
+            self.format_current(bar),
+            max
+        )];
+
+        lines.push(match report.upstream_window {
+            Some(window) => format!(
+                "upstream prefetch window 0x{:x}-0x{:x} [size={}]",
+                window.base,
+                window.limit,
+                format_size(window.limit - window.base + 1)
+            ),
+            None => String::from("no upstream prefetch window"),
+        });
+
+        if report.is_undersized_gpu() {
+            let verdict = match report.window_fits_max() {
+                Some(true) => "upstream window fits it",
+                Some(false) => "upstream window too small",
+                None => "no upstream window to check",
+            };
+            lines.push(format!("GPU BAR could grow to {}, {}", max, verdict));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OFFSET: u16 = 0x100;
+
+    // a capability with one entry per (capability, control) pair
+    fn read(entries: &[(u32, u32)]) -> ResizableBarCapability {
+        let mut bytes = vec![0; 0x200];
+        for (index, (capability, control)) in entries.iter().enumerate() {
+            let entry = OFFSET as usize + ENTRIES + index * ENTRY_LEN;
+            let control = match index {
+                0 => control | (entries.len() as u32) << 5,
+                _ => *control,
+            };
+            bytes[entry..entry + 4].copy_from_slice(&capability.to_le_bytes());
+            bytes[entry + 4..entry + 8].copy_from_slice(&control.to_le_bytes());
+        }
+        ResizableBarCapability::read(&ConfigSpace::from(bytes), OFFSET).unwrap()
+    }
+
+    // a control register for `bar` with the size encoding `size`
+    fn control(bar: u32, size: u32) -> u32 {
+        size << 8 | bar
+    }
+
+    #[test]
+    fn decodes_the_sizes() {
+        // 256M and 512M supported, 256M current
+        let cap = read(&[(0x3 << 12, control(0, 8))]);
+        let bar = cap.bars[0];
+        assert_eq!(bar.supported_sizes(), vec![256 << 20, 512 << 20]);
+        assert_eq!(bar.current_size, Some(256 << 20));
+        assert_eq!(bar.max_size(), Some(512 << 20));
+        assert!(bar.can_grow());
+    }
+
+    #[test]
+    fn reads_each_entry() {
+        let cap = read(&[(0x1 << 4, control(0, 0)), (0x1 << 12, control(2, 8))]);
+        assert_eq!(cap.bars.len(), 2);
+        assert_eq!(cap.bars[1].bar_index, 2);
+        assert_eq!(cap.bars[1].current_size, Some(256 << 20));
+        assert!(!cap.bars[1].can_grow());
+    }
+
+    #[test]
+    fn decodes_the_sizes_in_the_control_register() {
+        // bit 16 of the control register continues at 256T
+        let cap = read(&[(0, 0x1 << 16 | control(0, 0))]);
+        assert_eq!(cap.bars[0].supported_sizes(), vec![1 << 48]);
+        assert_eq!(cap.bars[0].current_size, Some(1 << 20));
+    }
+
+    #[test]
+    fn treats_oversized_encodings_as_reserved() {
+        let cap = read(&[(0x1 << 4, control(0, 43)), (0x1 << 4, control(1, 44))]);
+        assert_eq!(cap.bars[0].current_size, Some(1 << 63));
+        assert_eq!(cap.bars[1].current_size, None);
+        assert!(!cap.bars[1].can_grow());
+
+        let lines = ResizableBarPrettyPrinter::new().print(&cap);
+        assert_eq!(lines[1], "BAR 1: current reserved, supported 1M");
+    }
+
+    #[test]
+    fn fits_the_largest_size_in_the_window() {
+        let cap = read(&[(0x3 << 12, control(0, 8))]);
+        let report = |base: u64, limit: u64| ResizableBarReport {
+            bar: cap.bars[0],
+            is_gpu: true,
+            resource_size: Some(256 << 20),
+            upstream_window: Some(BridgeWindow {
+                base,
+                limit,
+                is_wide: true,
+            }),
+        };
+
+        // 512M aligned up from 0x1000_0000 ends at 0x3fff_ffff
+        assert_eq!(
+            report(0x1000_0000, 0x3fff_ffff).window_fits_max(),
+            Some(true)
+        );
+        assert_eq!(
+            report(0x1000_0000, 0x2fff_ffff).window_fits_max(),
+            Some(false)
+        );
+        assert_eq!(report(u64::MAX - 0xfff, u64::MAX).window_fits_max(), None);
+        assert!(report(0, 0).is_undersized_gpu());
+    }
+
+    #[test]
+    fn fails_past_the_end() {
+        let cf = ConfigSpace::from(vec![0; 0x100]);
+        assert_eq!(ResizableBarCapability::read(&cf, OFFSET), None);
+    }
+}
@@ -0,0 +1,236 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::bar::{BarDecoder, BarPrettyPrinter};
+use crate::config_space::shared::{
+    format_bitvec_flags, push_register_lines, BitVecFieldDescriptor,
+};
+use crate::config_space::{format_size, ConfigSpace};
+
+// register offsets relative to the start of the capability
+const CAPABILITIES: usize = 0x04;
+const CONTROL: usize = 0x08;
+const STATUS: usize = 0x0a;
+const INITIAL_VFS: usize = 0x0c;
+const TOTAL_VFS: usize = 0x0e;
+const NUM_VFS: usize = 0x10;
+const FUNCTION_DEPENDENCY_LINK: usize = 0x12;
+const FIRST_VF_OFFSET: usize = 0x14;
+const VF_STRIDE: usize = 0x16;
+const VF_DEVICE_ID: usize = 0x1a;
+const SUPPORTED_PAGE_SIZES: usize = 0x1c;
+const SYSTEM_PAGE_SIZE: usize = 0x20;
+const VF_BARS: usize = 0x24;
+const VF_MIGRATION_STATE: usize = 0x3c;
+
+const CAPABILITY_LEN: usize = 0x40;
+const VF_BAR_COUNT: usize = 6;
+
+const CAPABILITIES_FIELDS: [BitVecFieldDescriptor; 4] = [
+    // VF Migration Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Migration",
+        is_reserved: false,
+    },
+    // ARI Capable Hierarchy Preserved
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ARIPreserved",
+        is_reserved: false,
+    },
+    // VF 10-Bit Tag Requester Supported
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "10BitTagReq",
+        is_reserved: false,
+    },
+    // VF Migration Interrupt Message Number
+    BitVecFieldDescriptor {
+        len: 29,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+const CONTROL_FIELDS: [BitVecFieldDescriptor; 7] = [
+    // VF Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Enable",
+        is_reserved: false,
+    },
+    // VF Migration Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Migration",
+        is_reserved: false,
+    },
+    // VF Migration Interrupt Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Interrupt",
+        is_reserved: false,
+    },
+    // VF MSE
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MSE",
+        is_reserved: false,
+    },
+    // ARI Capable Hierarchy
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ARIHierarchy",
+        is_reserved: false,
+    },
+    // VF 10-Bit Tag Requester Enable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "10BitTagReq",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 10,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+];
+
+/// The Single Root I/O Virtualization extended capability (ID 0x0010),
+/// found on physical functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SriovCapability {
+    pub offset: u16,
+    pub capabilities: u32,
+    pub control: u16,
+    pub status: u16,
+    pub initial_vfs: u16,
+    pub total_vfs: u16,
+    pub num_vfs: u16,
+    pub function_dependency_link: u8,
+    pub first_vf_offset: u16,
+    pub vf_stride: u16,
+    pub vf_device_id: u16,
+    pub supported_page_sizes: u32,
+    pub system_page_size: u32,
+    pub vf_bars: [u32; VF_BAR_COUNT],
+    pub vf_migration_state: u32,
+}
+
+impl SriovCapability {
+    /// Reads the capability at `offset`, or `None` if it runs past the end
+    /// of the readable config space.
+    pub fn read(cf: &ConfigSpace, offset: u16) -> Option<Self> {
+        let base = offset as usize;
+        if base + CAPABILITY_LEN > cf.len() {
+            return None;
+        }
+
+        let mut vf_bars = [0; VF_BAR_COUNT];
+        for (index, bar) in vf_bars.iter_mut().enumerate() {
+            *bar = cf.dword(base + VF_BARS + index * 4);
+        }
+
+        Some(Self {
+            offset,
+            capabilities: cf.dword(base + CAPABILITIES),
+            control: cf.word(base + CONTROL),
+            status: cf.word(base + STATUS),
+            initial_vfs: cf.word(base + INITIAL_VFS),
+            total_vfs: cf.word(base + TOTAL_VFS),
+            num_vfs: cf.word(base + NUM_VFS),
+            function_dependency_link: cf.byte(base + FUNCTION_DEPENDENCY_LINK),
+            first_vf_offset: cf.word(base + FIRST_VF_OFFSET),
+            vf_stride: cf.word(base + VF_STRIDE),
+            vf_device_id: cf.word(base + VF_DEVICE_ID),
+            supported_page_sizes: cf.dword(base + SUPPORTED_PAGE_SIZES),
+            system_page_size: cf.dword(base + SYSTEM_PAGE_SIZE),
+            vf_bars,
+            vf_migration_state: cf.dword(base + VF_MIGRATION_STATE),
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.control & 0x1 > 0
+    }
+
+    /// Routing ID of VF `n` (counting from 0) relative to the PF.
+    pub fn vf_routing_id_offset(&self, n: u16) -> u32 {
+        self.first_vf_offset as u32 + n as u32 * self.vf_stride as u32
+    }
+}
+
+// bit n of the page size registers stands for a page size of 4K << n
+fn format_page_sizes(vector: u32) -> String {
+    let sizes: Vec<String> = (0..32)
+        .filter(|bit| vector & (1 << bit) > 0)
+        .map(|bit| format_size(4096u64 << bit))
+        .collect();
+    match sizes.is_empty() {
+        true => String::from("none"),
+        false => sizes.join(" "),
+    }
+}
+
+pub struct SriovPrettyPrinter {}
+
+impl Default for SriovPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SriovPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, cap: &SriovCapability) -> Vec<String> {
+        let mut lines = vec![];
+
+        let values = format!("Migration Interrupt Message {}", cap.capabilities >> 21);
+        let flags = format_bitvec_flags(cap.capabilities, &CAPABILITIES_FIELDS);
+        push_register_lines("IOVCap", values, flags, &mut lines);
+
+        let flags = format_bitvec_flags(cap.control as u32, &CONTROL_FIELDS);
+        push_register_lines("IOVCtl", String::new(), flags, &mut lines);
+
+        let status = match cap.status & 0x1 {
+            1 => "+Migration",
+            _ => "-Migration",
+        };
+        lines.push(format!("{:<9}: {}", "IOVSta", status));
+
+        lines.push(format!(
+            "{:<9}: Initial {}, Total {}, Number {}, Function Dependency Link {:02x}",
+            "VFs", cap.initial_vfs, cap.total_vfs, cap.num_vfs, cap.function_dependency_link
+        ));
+        lines.push(format!(
+            "{:<9}: First VF Offset {}, VF Stride {}, VF Device ID {:04x}",
+            "Routing", cap.first_vf_offset, cap.vf_stride, cap.vf_device_id
+        ));
+        lines.push(format!(
+            "{:<9}: Supported {}, System {}",
+            "PageSize",
+            format_page_sizes(cap.supported_page_sizes),
+            format_page_sizes(cap.system_page_size)
+        ));
+
+        let decoder = BarDecoder::new();
+        let printer = BarPrettyPrinter::new();
+        for bar in decoder.decode_registers(&cap.vf_bars, &[]) {
+            let name = format!("VF BAR{}", bar.index);
+            lines.push(format!("{:<9}: {}", name, printer.print(&bar)));
+        }
+
+        lines.push(format!(
+            "{:<9}: BIR {}, offset 0x{:08x}",
+            "MigState",
+            cap.vf_migration_state & 0x7,
+            cap.vf_migration_state & !0x7
+        ));
+
+        lines
+    }
+}
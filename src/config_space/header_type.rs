@@ -3,6 +3,10 @@ use alloc::{format, string::String, vec::Vec};
 
 use crate::config_space::shared::format_flags_line;
 
+pub const HEADER_LAYOUT_GENERAL: u8 = 0x00;
+pub const HEADER_LAYOUT_BRIDGE: u8 = 0x01;
+pub const HEADER_LAYOUT_CARDBUS: u8 = 0x02;
+
 struct FieldDescriptor {
     mask: u8,
     name: &'static str,
@@ -18,7 +22,7 @@ const HEADER_TYPE_FIELDS: [FieldDescriptor; 3] = [
         name: "PCItoCardBusBridge",
     },
     FieldDescriptor {
-        mask: 0xff,
+        mask: 0x80,
         name: "MultiFunction",
     },
 ];
@@ -33,6 +37,13 @@ impl From<u8> for HeaderTypeRegister {
     }
 }
 
+impl HeaderTypeRegister {
+    // bits 6:0 select the layout of the rest of the header
+    pub fn layout(&self) -> u8 {
+        self.vector & 0x7f
+    }
+}
+
 pub struct HeaderTypePrettyPrinter {}
 
 impl HeaderTypePrettyPrinter {
@@ -0,0 +1,502 @@
+use alloc::collections::BTreeMap;
+use alloc::{format, string::String, string::ToString};
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::config_space::classes::{class_name, prog_if_name, subclass_name};
+use crate::config_space::devices::device_name;
+use crate::config_space::subsystems::subsystem_name;
+use crate::config_space::vendors::vendor_name;
+
+// the entry the last indented line belongs to
+enum Section {
+    None,
+    Vendor(u16),
+    Device(u16, u16),
+    Class(u8),
+    Subclass(u8, u8),
+}
+
+/// A name from one of the ID tables, noting whether it came from the local
+/// overrides file. Local names are marked when displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdName<'a> {
+    pub name: &'a str,
+    pub local: bool,
+}
+
+impl fmt::Display for IdName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.local {
+            true => write!(f, "{} (local)", self.name),
+            false => write!(f, "{}", self.name),
+        }
+    }
+}
+
+// the names parsed from one pci.ids file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct IdTables {
+    vendors: BTreeMap<u16, String>,
+    devices: BTreeMap<(u16, u16), String>,
+    subsystems: BTreeMap<(u16, u16, u16, u16), String>,
+    classes: BTreeMap<u8, String>,
+    subclasses: BTreeMap<(u8, u8), String>,
+    prog_ifs: BTreeMap<(u8, u8, u8), String>,
+}
+
+fn parse_id<T: TryFrom<u32>>(text: &str, digits: usize) -> Option<T> {
+    if text.len() != digits {
+        return None;
+    }
+    let value = u32::from_str_radix(text, 16).ok()?;
+    T::try_from(value).ok()
+}
+
+// an ID and a name, separated by two spaces
+fn split_entry(line: &str) -> Option<(&str, &str)> {
+    let (id, name) = line.split_once("  ")?;
+    Some((id, name.trim()))
+}
+
+// the local name, then the one from the loaded file, then the embedded one
+fn find<'a, K: Ord>(
+    key: &K,
+    local: &'a BTreeMap<K, String>,
+    loaded: &'a BTreeMap<K, String>,
+    embedded: impl FnOnce() -> Option<&'static str>,
+) -> Option<IdName<'a>> {
+    if let Some(name) = local.get(key) {
+        return Some(IdName { name, local: true });
+    }
+    let name = match loaded.get(key) {
+        Some(name) => name.as_str(),
+        None => embedded()?,
+    };
+    Some(IdName { name, local: false })
+}
+
+impl IdTables {
+    fn parse(text: &str) -> Self {
+        let mut ids = Self::default();
+        let mut section = Section::None;
+
+        for line in text.lines() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let depth = line.len() - line.trim_start_matches('\t').len();
+            let entry = match split_entry(&line[depth..]) {
+                Some(entry) => entry,
+                None => continue,
+            };
+
+            section = match (depth, entry, &section) {
+                (0, (id, name), _) if id.starts_with("C ") => match parse_id(&id[2..], 2) {
+                    Some(class) => {
+                        ids.classes.insert(class, name.to_string());
+                        Section::Class(class)
+                    }
+                    None => Section::None,
+                },
+                (0, (id, name), _) => match parse_id(id, 4) {
+                    Some(vendor_id) => {
+                        ids.vendors.insert(vendor_id, name.to_string());
+                        Section::Vendor(vendor_id)
+                    }
+                    // other sections, like the device types in the I/O
+                    // classes ("X") of some versions
+                    None => Section::None,
+                },
+                (1, (id, name), Section::Vendor(vendor_id) | Section::Device(vendor_id, _)) => {
+                    match parse_id(id, 4) {
+                        Some(device_id) => {
+                            ids.devices
+                                .insert((*vendor_id, device_id), name.to_string());
+                            Section::Device(*vendor_id, device_id)
+                        }
+                        None => Section::Vendor(*vendor_id),
+                    }
+                }
+                (2, (id, name), Section::Device(vendor_id, device_id)) => {
+                    let subsystem = id.split_once(' ').and_then(|(subvendor, subdevice)| {
+                        Some((parse_id(subvendor, 4)?, parse_id(subdevice, 4)?))
+                    });
+                    if let Some((subvendor_id, subdevice_id)) = subsystem {
+                        ids.subsystems.insert(
+                            (*vendor_id, *device_id, subvendor_id, subdevice_id),
+                            name.to_string(),
+                        );
+                    }
+                    Section::Device(*vendor_id, *device_id)
+                }
+                (1, (id, name), Section::Class(class) | Section::Subclass(class, _)) => {
+                    match parse_id(id, 2) {
+                        Some(subclass) => {
+                            ids.subclasses.insert((*class, subclass), name.to_string());
+                            Section::Subclass(*class, subclass)
+                        }
+                        None => Section::Class(*class),
+                    }
+                }
+                (2, (id, name), Section::Subclass(class, subclass)) => {
+                    if let Some(prog_if) = parse_id(id, 2) {
+                        ids.prog_ifs
+                            .insert((*class, *subclass, prog_if), name.to_string());
+                    }
+                    Section::Subclass(*class, *subclass)
+                }
+                _ => section,
+            };
+        }
+
+        ids
+    }
+
+    fn len(&self) -> usize {
+        self.vendors.len()
+            + self.devices.len()
+            + self.subsystems.len()
+            + self.classes.len()
+            + self.subclasses.len()
+            + self.prog_ifs.len()
+    }
+}
+
+/// Names from a pci.ids file loaded at runtime, and from a local overrides
+/// file in the same format for hardware the public database doesn't know.
+/// Lookups try the local names first, then the loaded file, then the tables
+/// embedded at build time, so an empty `PciIds` gives just the embedded names.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PciIds {
+    loaded: IdTables,
+    local: IdTables,
+}
+
+impl PciIds {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the contents of a pci.ids file. Lines that don't fit the
+    /// format are skipped, as are sections other than devices and classes.
+    pub fn parse(text: &str) -> Self {
+        Self {
+            loaded: IdTables::parse(text),
+            local: IdTables::default(),
+        }
+    }
+
+    /// Adds local overrides in pci.ids syntax. Their names take priority
+    /// over every other source and are marked as local when displayed.
+    pub fn set_local(&mut self, text: &str) {
+        self.local = IdTables::parse(text);
+    }
+
+    /// The number of names loaded from both files, not counting the
+    /// embedded ones.
+    pub fn len(&self) -> usize {
+        self.loaded.len() + self.local.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn vendor(&self, vendor_id: u16) -> Option<IdName<'_>> {
+        find(
+            &vendor_id,
+            &self.local.vendors,
+            &self.loaded.vendors,
+            || vendor_name(vendor_id),
+        )
+    }
+
+    pub fn device(&self, vendor_id: u16, device_id: u16) -> Option<IdName<'_>> {
+        let key = (vendor_id, device_id);
+        find(&key, &self.local.devices, &self.loaded.devices, || {
+            device_name(vendor_id, device_id)
+        })
+    }
+
+    pub fn subsystem(
+        &self,
+        vendor_id: u16,
+        device_id: u16,
+        subvendor_id: u16,
+        subdevice_id: u16,
+    ) -> Option<IdName<'_>> {
+        let key = (vendor_id, device_id, subvendor_id, subdevice_id);
+        find(
+            &key,
+            &self.local.subsystems,
+            &self.loaded.subsystems,
+            || subsystem_name(vendor_id, device_id, subvendor_id, subdevice_id),
+        )
+    }
+
+    pub fn class(&self, class: u8) -> Option<IdName<'_>> {
+        find(&class, &self.local.classes, &self.loaded.classes, || {
+            class_name(class)
+        })
+    }
+
+    pub fn subclass(&self, class: u8, subclass: u8) -> Option<IdName<'_>> {
+        let key = (class, subclass);
+        find(
+            &key,
+            &self.local.subclasses,
+            &self.loaded.subclasses,
+            || subclass_name(class, subclass),
+        )
+    }
+
+    pub fn prog_if(&self, class: u8, subclass: u8, prog_if: u8) -> Option<IdName<'_>> {
+        let key = (class, subclass, prog_if);
+        find(&key, &self.local.prog_ifs, &self.loaded.prog_ifs, || {
+            prog_if_name(class, subclass, prog_if)
+        })
+    }
+
+    pub fn vendor_name(&self, vendor_id: u16) -> Option<&str> {
+        Some(self.vendor(vendor_id)?.name)
+    }
+
+    pub fn device_name(&self, vendor_id: u16, device_id: u16) -> Option<&str> {
+        Some(self.device(vendor_id, device_id)?.name)
+    }
+
+    pub fn subsystem_name(
+        &self,
+        vendor_id: u16,
+        device_id: u16,
+        subvendor_id: u16,
+        subdevice_id: u16,
+    ) -> Option<&str> {
+        Some(
+            self.subsystem(vendor_id, device_id, subvendor_id, subdevice_id)?
+                .name,
+        )
+    }
+
+    pub fn class_name(&self, class: u8) -> Option<&str> {
+        Some(self.class(class)?.name)
+    }
+
+    pub fn subclass_name(&self, class: u8, subclass: u8) -> Option<&str> {
+        Some(self.subclass(class, subclass)?.name)
+    }
+
+    pub fn prog_if_name(&self, class: u8, subclass: u8, prog_if: u8) -> Option<&str> {
+        Some(self.prog_if(class, subclass, prog_if)?.name)
+    }
+
+    /// Describes a class code the way lspci does, as in "Serial bus
+    /// controller / USB controller, prog-if 30 [XHCI]". Subclasses without a
+    /// name and a zero prog-if without a name are left out.
+    pub fn describe_class(&self, class: u8, subclass: u8, prog_if: u8) -> String {
+        let mut text = match self.class(class) {
+            Some(name) => name.to_string(),
+            None => format!("Class {:02x}", class),
+        };
+
+        if let Some(name) = self.subclass(class, subclass) {
+            text.push_str(&format!(" / {}", name));
+        }
+
+        match self.prog_if(class, subclass, prog_if) {
+            Some(name) => text.push_str(&format!(", prog-if {:02x} [{}]", prog_if, name)),
+            None if prog_if != 0 => text.push_str(&format!(", prog-if {:02x}", prog_if)),
+            None => {}
+        }
+
+        text
+    }
+
+    /// Describes a subsystem the way lspci's "Subsystem:" line does, as in
+    /// "Dell Ethernet 10G 4P X710 SFP+ rNDC". Without an exact match it
+    /// falls back to the subsystem vendor's name, as in "Dell Device 1f34".
+    pub fn describe_subsystem(
+        &self,
+        vendor_id: u16,
+        device_id: u16,
+        subvendor_id: u16,
+        subdevice_id: u16,
+    ) -> String {
+        let subvendor = self.vendor(subvendor_id);
+        let name = self.subsystem(vendor_id, device_id, subvendor_id, subdevice_id);
+
+        match (subvendor, name) {
+            (Some(subvendor), Some(name)) => format!("{} {}", subvendor, name),
+            (None, Some(name)) => name.to_string(),
+            (Some(subvendor), None) => format!("{} Device {:04x}", subvendor, subdevice_id),
+            (None, None) => format!("Device {:04x}:{:04x}", subvendor_id, subdevice_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDS: &str = "\
+# a comment
+ffe0  Example Vendor
+\t0001  Example NIC
+\t\t1af4 1100  Example NIC in QEMU
+\t\tzzzz 0001  Bad subvendor
+\t0002  Example GPU
+ffe1  Second Vendor
+C 0c  Serial bus controller
+\t03  USB controller
+\t\t30  XHCI
+\t80  Other serial bus
+";
+
+    #[test]
+    fn parses_vendors_and_devices() {
+        let ids = PciIds::parse(IDS);
+        assert_eq!(ids.vendor_name(0xffe0), Some("Example Vendor"));
+        assert_eq!(ids.vendor_name(0xffe1), Some("Second Vendor"));
+        assert_eq!(ids.device_name(0xffe0, 0x0001), Some("Example NIC"));
+        assert_eq!(ids.device_name(0xffe0, 0x0002), Some("Example GPU"));
+        assert_eq!(ids.device_name(0xffe1, 0x0001), None);
+    }
+
+    #[test]
+    fn parses_subsystems_under_their_device() {
+        let ids = PciIds::parse(IDS);
+        assert_eq!(
+            ids.subsystem_name(0xffe0, 0x0001, 0x1af4, 0x1100),
+            Some("Example NIC in QEMU")
+        );
+        assert_eq!(ids.subsystem_name(0xffe0, 0x0002, 0x1af4, 0x1100), None);
+    }
+
+    #[test]
+    fn parses_classes() {
+        let ids = PciIds::parse(IDS);
+        assert_eq!(ids.class_name(0x0c), Some("Serial bus controller"));
+        assert_eq!(ids.subclass_name(0x0c, 0x03), Some("USB controller"));
+        assert_eq!(ids.subclass_name(0x0c, 0x80), Some("Other serial bus"));
+        assert_eq!(ids.prog_if_name(0x0c, 0x03, 0x30), Some("XHCI"));
+        assert_eq!(
+            ids.describe_class(0x0c, 0x03, 0x30),
+            "Serial bus controller / USB controller, prog-if 30 [XHCI]"
+        );
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        let text = "\
+ffe0  Example Vendor
+\t001  Short device ID
+\t0001 One space only
+\t0002  Kept
+ffe  Short vendor ID
+\t0003  Device of a bad vendor
+C 1  Short class
+\t01  Subclass of a bad class
+X 01  Some other section
+";
+        let ids = PciIds::parse(text);
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids.vendor_name(0xffe0), Some("Example Vendor"));
+        assert_eq!(ids.device_name(0xffe0, 0x0002), Some("Kept"));
+        assert_eq!(ids.device_name(0xffe0, 0x0001), None);
+        assert_eq!(ids.device_name(0xffe0, 0x0003), None);
+        assert_eq!(ids.subsystem_name(0xffe0, 0x0002, 0x0001, 0x0000), None);
+    }
+
+    #[test]
+    fn local_names_win_and_are_marked() {
+        let mut ids = PciIds::parse(IDS);
+        ids.set_local(
+            "\
+ffe0  Our Vendor
+\t0003  Prototype card
+ffe2  Unreleased Vendor
+",
+        );
+
+        let vendor = ids.vendor(0xffe0).unwrap();
+        assert!(vendor.local);
+        assert_eq!(vendor.to_string(), "Our Vendor (local)");
+        assert_eq!(ids.vendor_name(0xffe2), Some("Unreleased Vendor"));
+        assert_eq!(
+            ids.device(0xffe0, 0x0003).unwrap().to_string(),
+            "Prototype card (local)"
+        );
+
+        // names the local file doesn't have still come from the loaded one
+        let device = ids.device(0xffe0, 0x0001).unwrap();
+        assert!(!device.local);
+        assert_eq!(device.to_string(), "Example NIC");
+        assert_eq!(ids.len(), 12);
+    }
+
+    #[cfg(feature = "ids")]
+    #[test]
+    fn local_names_override_the_embedded_tables() {
+        let mut ids = PciIds::new();
+        ids.set_local("1af4  Our Name For Red Hat\n");
+        assert_eq!(
+            ids.vendor(0x1af4).unwrap().to_string(),
+            "Our Name For Red Hat (local)"
+        );
+        assert_eq!(
+            ids.describe_subsystem(0xffe0, 0x0001, 0x1af4, 0x1100),
+            "Our Name For Red Hat (local) Device 1100"
+        );
+    }
+
+    #[cfg(feature = "ids")]
+    #[test]
+    fn falls_back_to_the_embedded_tables() {
+        let ids = PciIds::parse(IDS);
+        assert_eq!(ids.vendor_name(0x1af4), vendor_name(0x1af4));
+        assert!(ids.vendor_name(0x1af4).is_some());
+        assert_eq!(PciIds::new().class_name(0x0c), class_name(0x0c));
+        assert!(PciIds::new().class_name(0x0c).is_some());
+    }
+
+    // the tables build.rs embeds have to match what the runtime parser makes
+    // of the same file
+    #[cfg(feature = "ids")]
+    #[test]
+    fn embeds_what_the_runtime_parser_reads() {
+        use crate::config_space::database::tables;
+
+        let ids = IdTables::parse(include_str!("../../support/pci.ids"));
+        let embedded = tables();
+
+        fn same<K: Ord + Copy>(embedded: &[(K, &str)], parsed: &BTreeMap<K, String>) -> bool {
+            embedded.len() == parsed.len()
+                && embedded
+                    .iter()
+                    .zip(parsed)
+                    .all(|((key, name), (parsed_key, parsed_name))| {
+                        key == parsed_key && name == parsed_name
+                    })
+        }
+        assert!(same(embedded.vendors, &ids.vendors));
+        assert!(same(embedded.devices, &ids.devices));
+        assert!(same(embedded.subsystems, &ids.subsystems));
+        assert!(same(embedded.classes, &ids.classes));
+        assert!(same(embedded.subclasses, &ids.subclasses));
+        assert!(same(embedded.prog_ifs, &ids.prog_ifs));
+
+        let ids = PciIds::parse(include_str!("../../support/pci.ids"));
+        assert_eq!(ids.vendor_name(0x8086), vendor_name(0x8086));
+        assert_eq!(ids.device_name(0x10de, 0x1eb8), device_name(0x10de, 0x1eb8));
+        assert_eq!(
+            ids.subsystem_name(0x8086, 0x1533, 0x103c, 0x0003),
+            subsystem_name(0x8086, 0x1533, 0x103c, 0x0003)
+        );
+        assert_eq!(
+            ids.prog_if_name(0x01, 0x08, 0x02),
+            prog_if_name(0x01, 0x08, 0x02)
+        );
+    }
+}
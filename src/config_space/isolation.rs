@@ -0,0 +1,304 @@
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::capabilities::PortType;
+use crate::config_space::extended::{AccessControlServicesCapability, EXT_CAP_ID_ACS};
+use crate::config_space::ConfigSpace;
+use crate::Address;
+
+// the capability list starts after the header and the extended capabilities
+// after the first 256 bytes, which only root can read
+const HEADER_LEN: usize = 0x40;
+const EXTENDED_START: usize = 0x100;
+
+/// A member of an IOMMU group and the bridges above it, nearest first.
+/// Config spaces that couldn't be read are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsolationPath {
+    pub address: Address,
+    pub config_space: Option<ConfigSpace>,
+    pub bridges: Vec<(Address, Option<ConfigSpace>)>,
+}
+
+/// A reason the members of an IOMMU group can reach each other without
+/// going through the IOMMU.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IsolationReason {
+    // some config space was too short to see the ACS capability
+    Unreadable,
+    // functions of one device talking to each other, by bus and device
+    MultifunctionWithoutAcs(u8, u8),
+    NoAcs(Address),
+    // the isolation controls the port implements but doesn't enable
+    MissingAcsControls(Address, Vec<&'static str>),
+    ConventionalBridge(Address),
+}
+
+/// Why an IOMMU group has more than one member, judged by the ACS controls
+/// along the upstream paths of its members.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsolationReport {
+    pub reasons: BTreeSet<IsolationReason>,
+}
+
+fn read_acs(cf: &ConfigSpace) -> Option<AccessControlServicesCapability> {
+    let cap = cf.find_extended_capability(EXT_CAP_ID_ACS)?;
+    AccessControlServicesCapability::read(cf, cap.offset)
+}
+
+impl IsolationReport {
+    pub fn analyze(members: &[IsolationPath]) -> Self {
+        let mut reasons = BTreeSet::new();
+
+        for member in members {
+            let address = member.address;
+            let has_siblings = members.iter().any(|other| {
+                other.address != address
+                    && other.address.domain == address.domain
+                    && other.address.bus == address.bus
+                    && other.address.device == address.device
+            });
+            if has_siblings {
+                match &member.config_space {
+                    Some(cf) if cf.len() > EXTENDED_START => {
+                        if !matches!(read_acs(cf), Some(acs) if acs.is_isolating()) {
+                            reasons.insert(IsolationReason::MultifunctionWithoutAcs(
+                                address.bus,
+                                address.device,
+                            ));
+                        }
+                    }
+                    _ => {
+                        reasons.insert(IsolationReason::Unreadable);
+                    }
+                }
+            }
+
+            for (bridge, cf) in &member.bridges {
+                if let Some(reason) = Self::bridge_reason(*bridge, cf.as_ref()) {
+                    reasons.insert(reason);
+                }
+            }
+        }
+
+        Self { reasons }
+    }
+
+    // only ports leading to a single link can redirect peer-to-peer traffic,
+    // and conventional PCI bridges forward it with the bridge as requester
+    fn bridge_reason(bridge: Address, cf: Option<&ConfigSpace>) -> Option<IsolationReason> {
+        let cf = match cf {
+            Some(cf) if cf.len() > HEADER_LEN => cf,
+            _ => return Some(IsolationReason::Unreadable),
+        };
+
+        match cf.pci_express().map(|pcie| pcie.port_type()) {
+            Some(PortType::RootPort) | Some(PortType::DownstreamPort)
+                if cf.len() <= EXTENDED_START =>
+            {
+                Some(IsolationReason::Unreadable)
+            }
+            Some(PortType::RootPort) | Some(PortType::DownstreamPort) => match read_acs(cf) {
+                None => Some(IsolationReason::NoAcs(bridge)),
+                Some(acs) if !acs.is_isolating() => Some(IsolationReason::MissingAcsControls(
+                    bridge,
+                    acs.missing_isolation(),
+                )),
+                Some(_) => None,
+            },
+            Some(PortType::PcieToPciBridge) | None => {
+                Some(IsolationReason::ConventionalBridge(bridge))
+            }
+            Some(_) => None,
+        }
+    }
+}
+
+pub struct IsolationPrettyPrinter {}
+
+impl Default for IsolationPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IsolationPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print_reason(&self, reason: &IsolationReason) -> String {
+        match reason {
+            IsolationReason::Unreadable => String::from("config space not readable (run as root)"),
+            IsolationReason::MultifunctionWithoutAcs(bus, device) => format!(
+                "no ACS isolation on multifunction device {:02x}:{:02x}",
+                bus, device
+            ),
+            IsolationReason::NoAcs(bridge) => format!("no ACS on upstream port {}", bridge),
+            IsolationReason::MissingAcsControls(bridge, missing) => format!(
+                "ACS on upstream port {} lacks {}",
+                bridge,
+                missing.join(" ")
+            ),
+            IsolationReason::ConventionalBridge(bridge) => format!(
+                "conventional PCI bridge {} hides the requester of devices behind it",
+                bridge
+            ),
+        }
+    }
+
+    pub fn print(&self, report: &IsolationReport) -> Vec<String> {
+        if report.reasons.is_empty() {
+            return vec![String::from(
+                "shared, but not for any ACS reason visible from config space",
+            )];
+        }
+
+        report
+            .reasons
+            .iter()
+            .map(|reason| format!("shared: {}", self.print_reason(reason)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOT_PORT: u8 = 0x4;
+    const UPSTREAM_PORT: u8 = 0x5;
+    const ALL_CONTROLS: u16 = 0x1d;
+
+    // a PCI Express function of the given port type, with ACS capabilities
+    // and control at 0x100 if given
+    fn function(port_type: u8, acs: Option<(u16, u16)>) -> ConfigSpace {
+        let mut bytes = vec![0; 0x1000];
+        bytes[0x06] = 0x10;
+        bytes[0x0e] = 0x01;
+        bytes[0x34] = 0x40;
+        bytes[0x40] = 0x10;
+        bytes[0x42] = 0x2 | port_type << 4;
+        if let Some((capabilities, control)) = acs {
+            bytes[0x100..0x104].copy_from_slice(&0x0001_000du32.to_le_bytes());
+            bytes[0x104..0x106].copy_from_slice(&capabilities.to_le_bytes());
+            bytes[0x106..0x108].copy_from_slice(&control.to_le_bytes());
+        }
+        ConfigSpace::from(bytes)
+    }
+
+    fn member(
+        address: Address,
+        cf: Option<ConfigSpace>,
+        bridges: &[(Address, Option<ConfigSpace>)],
+    ) -> IsolationPath {
+        IsolationPath {
+            address,
+            config_space: cf,
+            bridges: bridges.to_vec(),
+        }
+    }
+
+    fn root_port() -> Address {
+        Address::new(0x00, 0x1c, 0)
+    }
+
+    // two devices on their own bus behind one port
+    fn analyze_behind(port: Option<ConfigSpace>) -> IsolationReport {
+        let bridges = [(root_port(), port)];
+        IsolationReport::analyze(&[
+            member(Address::new(0x03, 0, 0), Some(function(0, None)), &bridges),
+            member(Address::new(0x03, 1, 0), Some(function(0, None)), &bridges),
+        ])
+    }
+
+    #[test]
+    fn finds_nothing_behind_an_isolating_port() {
+        let report = analyze_behind(Some(function(
+            ROOT_PORT,
+            Some((ALL_CONTROLS, ALL_CONTROLS)),
+        )));
+        assert!(report.reasons.is_empty());
+        assert_eq!(
+            IsolationPrettyPrinter::new().print(&report),
+            vec!["shared, but not for any ACS reason visible from config space"]
+        );
+    }
+
+    #[test]
+    fn names_ports_without_acs() {
+        let report = analyze_behind(Some(function(ROOT_PORT, None)));
+        let expected = [IsolationReason::NoAcs(root_port())];
+        assert!(report.reasons.iter().eq(expected.iter()));
+        assert_eq!(
+            IsolationPrettyPrinter::new().print(&report),
+            vec!["shared: no ACS on upstream port 00:1c.0"]
+        );
+    }
+
+    #[test]
+    fn names_the_missing_controls() {
+        // ReqRedir implemented but disabled
+        let report = analyze_behind(Some(function(ROOT_PORT, Some((ALL_CONTROLS, 0x19)))));
+        let expected = [IsolationReason::MissingAcsControls(
+            root_port(),
+            vec!["ReqRedir"],
+        )];
+        assert!(report.reasons.iter().eq(expected.iter()));
+        assert_eq!(
+            IsolationPrettyPrinter::new().print(&report),
+            vec!["shared: ACS on upstream port 00:1c.0 lacks ReqRedir"]
+        );
+    }
+
+    #[test]
+    fn names_conventional_bridges() {
+        // no capability list at all
+        let bridge = ConfigSpace::from(vec![0; 0x100]);
+        let report = analyze_behind(Some(bridge));
+        let expected = [IsolationReason::ConventionalBridge(root_port())];
+        assert!(report.reasons.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn ignores_upstream_ports() {
+        let report = analyze_behind(Some(function(UPSTREAM_PORT, None)));
+        assert!(report.reasons.is_empty());
+    }
+
+    #[test]
+    fn reports_unreadable_ports() {
+        let report = analyze_behind(None);
+        let expected = [IsolationReason::Unreadable];
+        assert!(report.reasons.iter().eq(expected.iter()));
+
+        // the first 256 bytes don't reach the extended capabilities
+        let port = function(ROOT_PORT, Some((ALL_CONTROLS, ALL_CONTROLS)));
+        let header = port.slice(0, 0x100).unwrap().to_vec();
+        let report = analyze_behind(Some(ConfigSpace::from(header)));
+        assert!(report.reasons.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn checks_the_functions_of_one_device() {
+        let functions = |acs| {
+            IsolationReport::analyze(&[
+                member(Address::new(0x03, 0, 0), Some(function(0, acs)), &[]),
+                member(Address::new(0x03, 0, 1), Some(function(0, acs)), &[]),
+            ])
+        };
+
+        let report = functions(None);
+        let expected = [IsolationReason::MultifunctionWithoutAcs(0x03, 0)];
+        assert!(report.reasons.iter().eq(expected.iter()));
+        assert_eq!(
+            IsolationPrettyPrinter::new().print(&report),
+            vec!["shared: no ACS isolation on multifunction device 03:00"]
+        );
+
+        assert!(functions(Some((ALL_CONTROLS, ALL_CONTROLS)))
+            .reasons
+            .is_empty());
+    }
+}
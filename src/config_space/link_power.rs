@@ -0,0 +1,266 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::extended::{L1PmSubstatesCapability, EXT_CAP_ID_L1_PM_SUBSTATES};
+use crate::config_space::shared::{
+    format_bitvec_flags, push_register_lines, BitVecFieldDescriptor,
+};
+use crate::config_space::ConfigSpace;
+
+// link power states as bits of a mask, L0s and L1 as in LnkCap and LnkCtl
+const STATE_L0S: u8 = 0x01;
+const STATE_ASPM_L1: u8 = 0x02;
+const STATE_ASPM_L1_1: u8 = 0x04;
+const STATE_ASPM_L1_2: u8 = 0x08;
+const STATE_PCI_PM_L1_1: u8 = 0x10;
+const STATE_PCI_PM_L1_2: u8 = 0x20;
+
+// substate bits in L1SubCap and L1SubCtl1
+const SUBSTATES: [(u8, u8); 4] = [
+    (0x1, STATE_PCI_PM_L1_2),
+    (0x2, STATE_PCI_PM_L1_1),
+    (0x4, STATE_ASPM_L1_2),
+    (0x8, STATE_ASPM_L1_1),
+];
+const SUBSTATES_SUPPORTED: u32 = 0x10;
+
+const STATE_FIELDS: [BitVecFieldDescriptor; 6] = [
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "L0s",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "L1",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ASPM_L1.1",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ASPM_L1.2",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PCI-PM_L1.1",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "PCI-PM_L1.2",
+        is_reserved: false,
+    },
+];
+
+/// The link power states one end of a link supports and has enabled, as a
+/// mask of L0s, L1, ASPM L1.1/L1.2 and PCI-PM L1.1/L1.2 in bits 0-5.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkPowerStates {
+    pub supported: u8,
+    pub enabled: u8,
+}
+
+impl LinkPowerStates {
+    /// Reads the ASPM bits of the PCI Express capability and the L1 PM
+    /// Substates capability, or `None` if the function has no link.
+    pub fn read(cf: &ConfigSpace) -> Option<Self> {
+        let pcie = cf.pci_express().filter(|pcie| pcie.has_link())?;
+        let mut supported = pcie.aspm_support();
+        let mut enabled = pcie.aspm_control();
+
+        let l1pm = cf
+            .find_extended_capability(EXT_CAP_ID_L1_PM_SUBSTATES)
+            .and_then(|cap| L1PmSubstatesCapability::read(cf, cap.offset))
+            .filter(|l1pm| l1pm.capabilities & SUBSTATES_SUPPORTED > 0);
+        if let Some(l1pm) = l1pm {
+            for (bit, state) in SUBSTATES {
+                if l1pm.substates_support() & bit > 0 {
+                    supported |= state;
+                }
+                if l1pm.substates_control() & bit > 0 {
+                    enabled |= state;
+                }
+            }
+        }
+
+        Some(Self { supported, enabled })
+    }
+}
+
+/// The power states of a link seen from both ends. A state is only used
+/// when both partners enable it, except L0s, which each end enters for its
+/// own transmitter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkPowerReport {
+    // the downstream facing port
+    pub upstream: LinkPowerStates,
+    // function 0 of the device below it
+    pub downstream: LinkPowerStates,
+}
+
+impl LinkPowerReport {
+    pub fn analyze(upstream: &ConfigSpace, downstream: &ConfigSpace) -> Option<Self> {
+        Some(Self {
+            upstream: LinkPowerStates::read(upstream)?,
+            downstream: LinkPowerStates::read(downstream)?,
+        })
+    }
+
+    /// States both partners support, and L0s if either end does.
+    pub fn supported(&self) -> u8 {
+        let both = self.upstream.supported & self.downstream.supported;
+        let either = self.upstream.supported | self.downstream.supported;
+        both & !STATE_L0S | either & STATE_L0S
+    }
+
+    /// States both partners support and enable, and L0s if either end does.
+    /// The ASPM L1 substates only count when ASPM L1 itself is enabled, as
+    /// the link never enters them otherwise.
+    pub fn enabled(&self) -> u8 {
+        let upstream = self.upstream.enabled & self.upstream.supported;
+        let downstream = self.downstream.enabled & self.downstream.supported;
+        let enabled = upstream & downstream & !STATE_L0S | (upstream | downstream) & STATE_L0S;
+        match enabled & STATE_ASPM_L1 {
+            0 => enabled & !(STATE_ASPM_L1_1 | STATE_ASPM_L1_2),
+            _ => enabled,
+        }
+    }
+
+    /// States both partners support that aren't enabled on both ends, and
+    /// L0s if either end supports it without enabling it. The ASPM L1
+    /// substates count as unused while ASPM L1 is off.
+    pub fn unused(&self) -> u8 {
+        let upstream = self.upstream.supported & !self.upstream.enabled;
+        let downstream = self.downstream.supported & !self.downstream.enabled;
+        self.supported() & !self.enabled() & !STATE_L0S | (upstream | downstream) & STATE_L0S
+    }
+}
+
+pub struct LinkPowerPrettyPrinter {}
+
+impl Default for LinkPowerPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LinkPowerPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, report: &LinkPowerReport) -> Vec<String> {
+        let mut lines = vec![];
+
+        let rows = [
+            ("Supported", report.supported()),
+            ("Enabled", report.enabled()),
+            ("UpCtl", report.upstream.enabled),
+            ("DownCtl", report.downstream.enabled),
+        ];
+        for (name, states) in rows {
+            let flags = format_bitvec_flags(states as u32, &STATE_FIELDS);
+            push_register_lines(name, String::new(), flags, &mut lines);
+        }
+
+        let unused: Vec<&str> = STATE_FIELDS
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| report.unused() & (1 << bit) > 0)
+            .map(|(_, desc)| desc.name)
+            .collect();
+        if !unused.is_empty() {
+            lines.push(format!("supported but not enabled: {}", unused.join(" ")));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: u8 = 0x3f;
+
+    fn link(upstream: (u8, u8), downstream: (u8, u8)) -> LinkPowerReport {
+        LinkPowerReport {
+            upstream: LinkPowerStates {
+                supported: upstream.0,
+                enabled: upstream.1,
+            },
+            downstream: LinkPowerStates {
+                supported: downstream.0,
+                enabled: downstream.1,
+            },
+        }
+    }
+
+    #[test]
+    fn uses_states_both_ends_enable() {
+        let report = link((ALL, ALL), (ALL, ALL));
+        assert_eq!(report.supported(), ALL);
+        assert_eq!(report.enabled(), ALL);
+        assert_eq!(report.unused(), 0);
+    }
+
+    #[test]
+    fn needs_both_ends_for_l1() {
+        let report = link((ALL, STATE_ASPM_L1), (ALL, 0));
+        assert_eq!(report.enabled(), 0);
+        assert_eq!(report.unused(), ALL);
+
+        // and both ends to support it
+        let report = link((ALL, ALL), (STATE_L0S, ALL));
+        assert_eq!(report.supported(), STATE_L0S);
+        assert_eq!(report.enabled(), STATE_L0S);
+    }
+
+    #[test]
+    fn counts_l0s_per_end() {
+        // L0s is supported and enabled on the upstream end only
+        let report = link((STATE_L0S, STATE_L0S), (0, 0));
+        assert_eq!(report.supported(), STATE_L0S);
+        assert_eq!(report.enabled(), STATE_L0S);
+        assert_eq!(report.unused(), 0);
+
+        // supported on both ends, enabled on one
+        let report = link((STATE_L0S, STATE_L0S), (STATE_L0S, 0));
+        assert_eq!(report.enabled(), STATE_L0S);
+        assert_eq!(report.unused(), STATE_L0S);
+
+        // enabled without being supported doesn't count
+        let report = link((0, STATE_L0S), (0, 0));
+        assert_eq!(report.supported(), 0);
+        assert_eq!(report.enabled(), 0);
+    }
+
+    #[test]
+    fn ignores_aspm_substates_without_aspm_l1() {
+        let substates = STATE_ASPM_L1_1 | STATE_ASPM_L1_2;
+        let report = link((ALL, substates), (ALL, substates));
+        assert_eq!(report.enabled(), 0);
+        assert_eq!(report.unused(), ALL);
+
+        // the PCI-PM substates don't depend on ASPM
+        let pci_pm = STATE_PCI_PM_L1_1 | STATE_PCI_PM_L1_2;
+        let report = link((ALL, substates | pci_pm), (ALL, substates | pci_pm));
+        assert_eq!(report.enabled(), pci_pm);
+        assert_eq!(report.unused(), ALL & !pci_pm);
+    }
+
+    #[test]
+    fn prints_the_unused_states() {
+        let report = link((ALL, STATE_ASPM_L1_1), (ALL, STATE_ASPM_L1_1));
+        let lines = LinkPowerPrettyPrinter::new().print(&report);
+        assert_eq!(
+            lines.last().unwrap(),
+            "supported but not enabled: L0s L1 ASPM_L1.1 ASPM_L1.2 PCI-PM_L1.1 PCI-PM_L1.2"
+        );
+    }
+}
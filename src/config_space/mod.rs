@@ -1,11 +1,63 @@
+mod bar;
+mod bridge_control;
+mod bridge_window;
+mod capabilities;
+mod classes;
 mod command;
+mod database;
 mod devices;
+mod extended;
 mod header_type;
+mod ids;
+mod isolation;
+mod link_power;
+mod resource;
+mod rom;
+mod secondary_status;
 mod shared;
 mod space;
 mod status;
+mod subsystems;
+mod vendor_specific;
 mod vendors;
+mod vpd;
 
+pub use bar::{
+    format_size, BarDecoder, BarKind, BarPrettyPrinter, BaseAddressRegister, ExpansionRom,
+};
+pub use bridge_window::{BridgeWindow, BridgeWindows};
+pub use capabilities::{
+    aspm_name, capability_name, link_speed_name, Capability, CapabilityError, CapabilityIter,
+    CapabilityPrettyPrinter, MsiCapability, MsiPrettyPrinter, MsixCapability, PciExpressCapability,
+    PciExpressPrettyPrinter, PortType, PowerManagementCapability, PowerManagementPrettyPrinter,
+    PowerState, VpdCapability, VpdCapabilityPrettyPrinter,
+};
+pub use classes::{class_name, describe_class, prog_if_name, subclass_name};
 pub use command::CommandRegister;
-pub use space::ConfigSpace;
+pub use database::id_database_version;
+pub use extended::{
+    extended_capability_name, AccessControlServicesCapability, AccessControlServicesPrettyPrinter,
+    AddressTranslationServicesCapability, AddressTranslationServicesPrettyPrinter,
+    AdvancedErrorReportingCapability, AdvancedErrorReportingPrettyPrinter,
+    DownstreamPortContainmentCapability, DownstreamPortContainmentPrettyPrinter,
+    ExtendedCapability, ExtendedCapabilityError, ExtendedCapabilityIter,
+    ExtendedCapabilityPrettyPrinter, L1PmSubstatesCapability, L1PmSubstatesPrettyPrinter,
+    LatencyToleranceReportingCapability, LatencyToleranceReportingPrettyPrinter,
+    PageRequestInterfaceCapability, PageRequestInterfacePrettyPrinter, PasidCapability,
+    PasidPrettyPrinter, PrecisionTimeMeasurementCapability, PrecisionTimeMeasurementPrettyPrinter,
+    ResizableBar, ResizableBarCapability, ResizableBarPrettyPrinter, ResizableBarReport,
+    RpPioRegisters, SriovCapability, SriovPrettyPrinter, EXT_CAP_ID_ACS, EXT_CAP_ID_RESIZABLE_BAR,
+};
+pub use ids::PciIds;
+pub use isolation::{IsolationPath, IsolationPrettyPrinter, IsolationReason, IsolationReport};
+pub use link_power::{LinkPowerPrettyPrinter, LinkPowerReport, LinkPowerStates};
+pub use resource::ResourceSizes;
+pub use rom::{EfiImageHeader, OptionRom, OptionRomPrettyPrinter, RomCodeType, RomError, RomImage};
 pub use space::ConfigSpacePrettyPrinter;
+pub use space::{ConfigSpace, ConfigSpaceError};
+pub use subsystems::{describe_subsystem, subsystem_name};
+pub use vendor_specific::{
+    VendorCapability, VendorCapabilityDecoder, VendorCapabilityKey, VendorCapabilityPrettyPrinter,
+    VendorCapabilityRegistry,
+};
+pub use vpd::{vpd_keyword_name, VitalProductData, VpdError, VpdPrettyPrinter};
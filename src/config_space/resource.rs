@@ -0,0 +1,58 @@
+// BARs 0-5 followed by the expansion ROM
+const RESOURCE_COUNT: usize = 7;
+
+/// Region sizes as reported by the kernel. Sizes can't be derived from a
+/// snapshot of config space, since that requires writing all ones to each
+/// BAR and reading back the mask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResourceSizes {
+    sizes: [Option<u64>; RESOURCE_COUNT],
+}
+
+impl ResourceSizes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the sysfs `resource` file: one `start end flags` line per
+    /// resource, with all values in hex.
+    pub fn from_sysfs_resource(text: &str) -> Self {
+        let mut sizes = Self::new();
+
+        for (index, line) in text.lines().take(RESOURCE_COUNT).enumerate() {
+            let mut columns = line.split_whitespace();
+            let start = columns.next().and_then(parse_hex);
+            let end = columns.next().and_then(parse_hex);
+
+            if let (Some(start), Some(end)) = (start, end) {
+                if end > start {
+                    sizes.sizes[index] = Some(end - start + 1);
+                }
+            }
+        }
+
+        sizes
+    }
+
+    /// Parses one line of `/proc/bus/pci/devices`: bus/devfn, vendor/device,
+    /// irq, seven base addresses and then seven sizes.
+    pub fn from_proc_devices_line(line: &str) -> Self {
+        let mut sizes = Self::new();
+
+        let columns = line.split_whitespace().skip(3 + RESOURCE_COUNT);
+        for (index, column) in columns.take(RESOURCE_COUNT).enumerate() {
+            sizes.sizes[index] = parse_hex(column).filter(|size| *size > 0);
+        }
+
+        sizes
+    }
+
+    pub fn get(&self, index: usize) -> Option<u64> {
+        self.sizes.get(index).copied().flatten()
+    }
+}
+
+fn parse_hex(value: &str) -> Option<u64> {
+    let digits = value.trim_start_matches("0x");
+    u64::from_str_radix(digits, 16).ok()
+}
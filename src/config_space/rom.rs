@@ -0,0 +1,505 @@
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::{format_size, ConfigSpace};
+
+const ROM_SIGNATURE: [u8; 2] = [0x55, 0xaa];
+const PCIR_SIGNATURE: &[u8; 4] = b"PCIR";
+const EFI_SIGNATURE: u32 = 0x0ef1;
+
+// images are sized and aligned in units of 512 bytes
+const ROM_UNIT: usize = 512;
+
+// offsets into the ROM image header
+const EFI_SIGNATURE_OFFSET: usize = 0x04;
+const EFI_SUBSYSTEM: usize = 0x08;
+const EFI_MACHINE_TYPE: usize = 0x0a;
+const EFI_COMPRESSION_TYPE: usize = 0x0c;
+const EFI_IMAGE_OFFSET: usize = 0x16;
+const PCIR_POINTER: usize = 0x18;
+const IMAGE_HEADER_LEN: usize = 0x1a;
+
+// offsets into the PCI Data Structure
+const PCIR_VENDOR_ID: usize = 0x04;
+const PCIR_DEVICE_ID: usize = 0x06;
+const PCIR_DEVICE_LIST: usize = 0x08;
+const PCIR_REVISION: usize = 0x0c;
+const PCIR_CLASS_CODE: usize = 0x0d;
+const PCIR_IMAGE_LENGTH: usize = 0x10;
+const PCIR_CODE_REVISION: usize = 0x12;
+const PCIR_CODE_TYPE: usize = 0x14;
+const PCIR_INDICATOR: usize = 0x15;
+const PCIR_LEN: usize = 0x18;
+
+// device ID and vendor ID in the config space header
+const VENDOR_ID_OFFSET: usize = 0x00;
+const DEVICE_ID_OFFSET: usize = 0x02;
+
+const EFI_SUBSYSTEMS: [(u16, &str); 3] = [
+    (10, "EFI Application"),
+    (11, "EFI Boot Service Driver"),
+    (12, "EFI Runtime Driver"),
+];
+
+const EFI_MACHINE_TYPES: [(u16, &str); 8] = [
+    (0x014c, "IA-32"),
+    (0x01c2, "ARM"),
+    (0x0200, "Itanium"),
+    (0x0ebc, "EFI Byte Code"),
+    (0x5032, "RISC-V 32"),
+    (0x5064, "RISC-V 64"),
+    (0x8664, "x64"),
+    (0xaa64, "AArch64"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RomCodeType {
+    X86,
+    OpenFirmware,
+    HpPaRisc,
+    Efi,
+    Unknown(u8),
+}
+
+impl From<u8> for RomCodeType {
+    fn from(value: u8) -> Self {
+        match value {
+            0x00 => RomCodeType::X86,
+            0x01 => RomCodeType::OpenFirmware,
+            0x02 => RomCodeType::HpPaRisc,
+            0x03 => RomCodeType::Efi,
+            _ => RomCodeType::Unknown(value),
+        }
+    }
+}
+
+impl RomCodeType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RomCodeType::X86 => "x86 PC-AT",
+            RomCodeType::OpenFirmware => "Open Firmware",
+            RomCodeType::HpPaRisc => "HP PA RISC",
+            RomCodeType::Efi => "EFI",
+            RomCodeType::Unknown(_) => "Unknown",
+        }
+    }
+}
+
+/// The EFI specific fields of the image header of an EFI image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EfiImageHeader {
+    pub subsystem: u16,
+    pub machine_type: u16,
+    pub compression_type: u16,
+    // offset of the EFI image from the start of the ROM image
+    pub image_offset: u16,
+}
+
+impl EfiImageHeader {
+    pub fn subsystem_name(&self) -> &'static str {
+        lookup(&EFI_SUBSYSTEMS, self.subsystem)
+    }
+
+    pub fn machine_type_name(&self) -> &'static str {
+        lookup(&EFI_MACHINE_TYPES, self.machine_type)
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        self.compression_type == 1
+    }
+}
+
+fn lookup(table: &[(u16, &'static str)], value: u16) -> &'static str {
+    table
+        .iter()
+        .find(|(key, _)| *key == value)
+        .map(|(_, name)| *name)
+        .unwrap_or("Unknown")
+}
+
+/// One image of an option ROM, as described by its PCI Data Structure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RomImage {
+    // offset of the image in the ROM
+    pub offset: usize,
+    pub vendor_id: u16,
+    pub device_id: u16,
+    // further device IDs the image supports, PCIR revision 3 and later
+    pub device_list: Vec<u16>,
+    pub pcir_revision: u8,
+    // base class, subclass and programming interface
+    pub class_code: u32,
+    // in bytes
+    pub image_length: usize,
+    pub code_revision: u16,
+    pub code_type: RomCodeType,
+    pub is_last: bool,
+    pub efi: Option<EfiImageHeader>,
+}
+
+impl RomImage {
+    pub fn supports(&self, vendor_id: u16, device_id: u16) -> bool {
+        self.vendor_id == vendor_id
+            && (self.device_id == device_id || self.device_list.contains(&device_id))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RomError {
+    // no 0x55aa at the start of an image, at this offset
+    MissingSignature(usize),
+    // the PCIR pointer doesn't lead to a PCI Data Structure
+    MissingPcir(usize),
+    // an image runs past the end of the ROM
+    Truncated(usize),
+    // an image has a length of zero, but isn't marked as the last one
+    ZeroLength(usize),
+}
+
+/// The images of a PCI option ROM, as read from the `rom` file in sysfs.
+/// When the walk fails the images before the failure are kept.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OptionRom {
+    pub images: Vec<RomImage>,
+    pub error: Option<RomError>,
+}
+
+impl OptionRom {
+    pub fn parse(bytes: &[u8]) -> Self {
+        let mut rom = Self::default();
+        let mut offset = 0;
+
+        loop {
+            match parse_image(bytes, offset) {
+                Ok(image) => {
+                    let is_last = image.is_last;
+                    let length = image.image_length;
+                    rom.images.push(image);
+
+                    if offset + length > bytes.len() {
+                        rom.error = Some(RomError::Truncated(offset));
+                        break;
+                    }
+                    if is_last || offset + length == bytes.len() {
+                        break;
+                    }
+                    if length == 0 {
+                        rom.error = Some(RomError::ZeroLength(offset));
+                        break;
+                    }
+                    offset += length;
+                }
+                Err(error) => {
+                    rom.error = Some(error);
+                    break;
+                }
+            }
+        }
+
+        rom
+    }
+
+    /// The images that don't name the vendor and device of `cf`.
+    pub fn mismatched_images(&self, cf: &ConfigSpace) -> Vec<&RomImage> {
+        let vendor_id = cf.word(VENDOR_ID_OFFSET);
+        let device_id = cf.word(DEVICE_ID_OFFSET);
+
+        self.images
+            .iter()
+            .filter(|image| !image.supports(vendor_id, device_id))
+            .collect()
+    }
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
+
+fn parse_image(bytes: &[u8], offset: usize) -> Result<RomImage, RomError> {
+    if offset + IMAGE_HEADER_LEN > bytes.len() {
+        return Err(RomError::Truncated(offset));
+    }
+    if bytes[offset..offset + 2] != ROM_SIGNATURE {
+        return Err(RomError::MissingSignature(offset));
+    }
+
+    let pcir = offset + read_u16(bytes, offset + PCIR_POINTER) as usize;
+    if pcir + PCIR_LEN > bytes.len() {
+        return Err(RomError::Truncated(offset));
+    }
+    if &bytes[pcir..pcir + 4] != PCIR_SIGNATURE {
+        return Err(RomError::MissingPcir(offset));
+    }
+
+    let pcir_revision = bytes[pcir + PCIR_REVISION];
+    let mut device_list = vec![];
+    let list_pointer = read_u16(bytes, pcir + PCIR_DEVICE_LIST) as usize;
+    if pcir_revision >= 3 && list_pointer > 0 {
+        // zero terminated, silently cut short at the end of the ROM
+        let mut position = pcir + list_pointer;
+        while position + 2 <= bytes.len() {
+            let device_id = read_u16(bytes, position);
+            if device_id == 0 {
+                break;
+            }
+            device_list.push(device_id);
+            position += 2;
+        }
+    }
+
+    let code_type = RomCodeType::from(bytes[pcir + PCIR_CODE_TYPE]);
+    let efi = match code_type {
+        RomCodeType::Efi if read_u32(bytes, offset + EFI_SIGNATURE_OFFSET) == EFI_SIGNATURE => {
+            Some(EfiImageHeader {
+                subsystem: read_u16(bytes, offset + EFI_SUBSYSTEM),
+                machine_type: read_u16(bytes, offset + EFI_MACHINE_TYPE),
+                compression_type: read_u16(bytes, offset + EFI_COMPRESSION_TYPE),
+                image_offset: read_u16(bytes, offset + EFI_IMAGE_OFFSET),
+            })
+        }
+        _ => None,
+    };
+
+    let class_code = bytes[pcir + PCIR_CLASS_CODE] as u32
+        | (bytes[pcir + PCIR_CLASS_CODE + 1] as u32) << 8
+        | (bytes[pcir + PCIR_CLASS_CODE + 2] as u32) << 16;
+
+    Ok(RomImage {
+        offset,
+        vendor_id: read_u16(bytes, pcir + PCIR_VENDOR_ID),
+        device_id: read_u16(bytes, pcir + PCIR_DEVICE_ID),
+        device_list,
+        pcir_revision,
+        class_code,
+        image_length: read_u16(bytes, pcir + PCIR_IMAGE_LENGTH) as usize * ROM_UNIT,
+        code_revision: read_u16(bytes, pcir + PCIR_CODE_REVISION),
+        code_type,
+        is_last: bytes[pcir + PCIR_INDICATOR] & 0x80 > 0,
+        efi,
+    })
+}
+
+pub struct OptionRomPrettyPrinter {}
+
+impl Default for OptionRomPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OptionRomPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print_image(&self, image: &RomImage) -> Vec<String> {
+        let mut lines = vec![];
+
+        let last = match image.is_last {
+            true => ", last",
+            false => "",
+        };
+        lines.push(format!(
+            "image at 0x{:x}: {} [size={}]{}",
+            image.offset,
+            image.code_type.name(),
+            format_size(image.image_length as u64),
+            last
+        ));
+        lines.push(format!(
+            "  {:04x}:{:04x}, class {:06x}, code revision 0x{:04x}, PCIR revision {}",
+            image.vendor_id,
+            image.device_id,
+            image.class_code,
+            image.code_revision,
+            image.pcir_revision
+        ));
+
+        if !image.device_list.is_empty() {
+            let ids: Vec<String> = image
+                .device_list
+                .iter()
+                .map(|device_id| format!("{:04x}", device_id))
+                .collect();
+            lines.push(format!("  also for devices {}", ids.join(" ")));
+        }
+
+        if let Some(efi) = &image.efi {
+            let compressed = match efi.is_compressed() {
+                true => "compressed",
+                false => "uncompressed",
+            };
+            lines.push(format!(
+                "  {}, {}, {}",
+                efi.subsystem_name(),
+                efi.machine_type_name(),
+                compressed
+            ));
+        }
+
+        lines
+    }
+
+    pub fn print_error(&self, error: &RomError) -> String {
+        match error {
+            RomError::MissingSignature(offset) => {
+                format!("no ROM signature at 0x{:x}", offset)
+            }
+            RomError::MissingPcir(offset) => {
+                format!("no PCI data structure for image at 0x{:x}", offset)
+            }
+            RomError::Truncated(offset) => format!("image at 0x{:x} truncated", offset),
+            RomError::ZeroLength(offset) => {
+                format!("image at 0x{:x} has zero length but isn't last", offset)
+            }
+        }
+    }
+
+    /// Prints the images of `rom`, flagging those that don't belong to the
+    /// device `cf`, if given.
+    pub fn print(&self, rom: &OptionRom, cf: Option<&ConfigSpace>) -> Vec<String> {
+        let mut lines = vec![];
+
+        for image in &rom.images {
+            lines.extend(self.print_image(image));
+        }
+
+        if let Some(cf) = cf {
+            for image in rom.mismatched_images(cf) {
+                lines.push(format!(
+                    "image at 0x{:x} is for {:04x}:{:04x}, not this device {:04x}:{:04x}",
+                    image.offset,
+                    image.vendor_id,
+                    image.device_id,
+                    cf.word(VENDOR_ID_OFFSET),
+                    cf.word(DEVICE_ID_OFFSET)
+                ));
+            }
+        }
+
+        if let Some(error) = &rom.error {
+            lines.push(self.print_error(error));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PCIR: usize = 0x1c;
+
+    // one 512 byte image for 8086:1572 with its PCI Data Structure at 0x1c
+    fn image(code_type: u8, is_last: bool) -> Vec<u8> {
+        let mut bytes = vec![0; ROM_UNIT];
+        bytes[..2].copy_from_slice(&ROM_SIGNATURE);
+        bytes[PCIR_POINTER] = PCIR as u8;
+        bytes[PCIR..PCIR + 4].copy_from_slice(PCIR_SIGNATURE);
+        bytes[PCIR + PCIR_VENDOR_ID..][..2].copy_from_slice(&0x8086u16.to_le_bytes());
+        bytes[PCIR + PCIR_DEVICE_ID..][..2].copy_from_slice(&0x1572u16.to_le_bytes());
+        bytes[PCIR + PCIR_CLASS_CODE..][..3].copy_from_slice(&[0x00, 0x00, 0x02]);
+        bytes[PCIR + PCIR_IMAGE_LENGTH] = 1;
+        bytes[PCIR + PCIR_CODE_TYPE] = code_type;
+        bytes[PCIR + PCIR_INDICATOR] = if is_last { 0x80 } else { 0x00 };
+        bytes
+    }
+
+    #[test]
+    fn parses_an_image() {
+        let rom = OptionRom::parse(&image(0x00, true));
+        assert_eq!(rom.error, None);
+        assert_eq!(rom.images.len(), 1);
+
+        let image = &rom.images[0];
+        assert_eq!((image.vendor_id, image.device_id), (0x8086, 0x1572));
+        assert_eq!(image.class_code, 0x020000);
+        assert_eq!(image.image_length, ROM_UNIT);
+        assert_eq!(image.code_type, RomCodeType::X86);
+        assert!(image.is_last);
+        assert!(image.supports(0x8086, 0x1572));
+        assert!(!image.supports(0x8086, 0x1573));
+    }
+
+    #[test]
+    fn follows_the_images_to_the_last() {
+        let mut bytes = image(0x00, false);
+        let mut efi = image(0x03, true);
+        efi[EFI_SIGNATURE_OFFSET..][..4].copy_from_slice(&EFI_SIGNATURE.to_le_bytes());
+        efi[EFI_SUBSYSTEM] = 11;
+        efi[EFI_MACHINE_TYPE..][..2].copy_from_slice(&0x8664u16.to_le_bytes());
+        bytes.extend(efi);
+        // padding after the last image is ignored
+        bytes.extend(vec![0xff; ROM_UNIT]);
+
+        let rom = OptionRom::parse(&bytes);
+        assert_eq!(rom.error, None);
+        assert_eq!(rom.images.len(), 2);
+        assert_eq!(rom.images[1].offset, ROM_UNIT);
+
+        let efi = rom.images[1].efi.unwrap();
+        assert_eq!(efi.subsystem_name(), "EFI Boot Service Driver");
+        assert_eq!(efi.machine_type, 0x8664);
+    }
+
+    #[test]
+    fn reads_the_device_list() {
+        let mut bytes = image(0x00, true);
+        bytes[PCIR + PCIR_REVISION] = 3;
+        bytes[PCIR + PCIR_DEVICE_LIST] = 0x20;
+        bytes[PCIR + 0x20..][..6].copy_from_slice(&[0x73, 0x15, 0x74, 0x15, 0x00, 0x00]);
+
+        let rom = OptionRom::parse(&bytes);
+        assert_eq!(rom.images[0].device_list, vec![0x1573, 0x1574]);
+        assert!(rom.images[0].supports(0x8086, 0x1574));
+    }
+
+    #[test]
+    fn keeps_the_images_before_an_error() {
+        let mut bytes = image(0x00, false);
+        bytes.extend(vec![0xff; ROM_UNIT]);
+        let rom = OptionRom::parse(&bytes);
+        assert_eq!(rom.images.len(), 1);
+        assert_eq!(rom.error, Some(RomError::MissingSignature(ROM_UNIT)));
+    }
+
+    #[test]
+    fn reports_bad_images() {
+        let rom = OptionRom::parse(&[0xff; ROM_UNIT]);
+        assert_eq!(rom.error, Some(RomError::MissingSignature(0)));
+
+        let mut bytes = image(0x00, true);
+        bytes[PCIR] = b'X';
+        assert_eq!(
+            OptionRom::parse(&bytes).error,
+            Some(RomError::MissingPcir(0))
+        );
+
+        let mut bytes = image(0x00, true);
+        bytes[PCIR + PCIR_IMAGE_LENGTH] = 2;
+        assert_eq!(OptionRom::parse(&bytes).error, Some(RomError::Truncated(0)));
+
+        let mut bytes = image(0x00, false);
+        bytes[PCIR + PCIR_IMAGE_LENGTH] = 0;
+        bytes.extend(image(0x00, true));
+        assert_eq!(
+            OptionRom::parse(&bytes).error,
+            Some(RomError::ZeroLength(0))
+        );
+
+        assert_eq!(
+            OptionRom::parse(&[0x55, 0xaa]).error,
+            Some(RomError::Truncated(0))
+        );
+    }
+}
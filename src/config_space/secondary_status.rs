@@ -0,0 +1,111 @@
+use alloc::{format, string::String};
+
+use crate::config_space::shared::format_bitvec_flags;
+use crate::config_space::status::devsel_name;
+
+use super::shared::BitVecFieldDescriptor;
+
+const SECONDARY_STATUS_FIELDS: [BitVecFieldDescriptor; 11] = [
+    BitVecFieldDescriptor {
+        len: 5,
+        name: "Reserved 1",
+        is_reserved: true,
+    },
+    // 66 MHz Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "66MHz",
+        is_reserved: false,
+    },
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "Reserved 2",
+        is_reserved: true,
+    },
+    // Fast Back-to-Back Transactions Capable
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "FastB2B",
+        is_reserved: false,
+    },
+    // Master Data Parity Error
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "MasterDataParErr",
+        is_reserved: false,
+    },
+    // DEVSEL Timing, a 2-bit field printed separately
+    BitVecFieldDescriptor {
+        len: 2,
+        name: "DEVSEL",
+        is_reserved: true,
+    },
+    // Signaled Target Abort
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "SigTAbrt",
+        is_reserved: false,
+    },
+    // Received Target Abort
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "RecvTAbrt",
+        is_reserved: false,
+    },
+    // Received Master Abort
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "RecvMAbrt",
+        is_reserved: false,
+    },
+    // Received System Error
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "RecvSysErr",
+        is_reserved: false,
+    },
+    // Detected Parity Error
+    BitVecFieldDescriptor {
+        len: 1,
+        name: "ParErr",
+        is_reserved: false,
+    },
+];
+
+pub struct SecondaryStatusRegister {
+    vector: u16,
+}
+
+impl From<u16> for SecondaryStatusRegister {
+    fn from(value: u16) -> Self {
+        Self { vector: value }
+    }
+}
+
+pub struct SecondaryStatusPrettyPrinter {}
+
+impl SecondaryStatusPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(&self, reg: &SecondaryStatusRegister) -> String {
+        let flags = format_bitvec_flags(reg.vector as u32, &SECONDARY_STATUS_FIELDS);
+        format!("{} DEVSEL={}", flags, devsel_name(reg.vector))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_the_devsel_timing() {
+        let reg = SecondaryStatusRegister::from(0x0420);
+        assert_eq!(
+            SecondaryStatusPrettyPrinter::new().print(&reg),
+            "+66MHz -FastB2B -MasterDataParErr -SigTAbrt -RecvTAbrt -RecvMAbrt \
+             -RecvSysErr -ParErr DEVSEL=slow"
+        );
+    }
+}
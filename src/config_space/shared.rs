@@ -1,5 +1,6 @@
 use alloc::format;
 use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
 
 pub struct BitVecFieldDescriptor {
@@ -9,9 +10,9 @@ pub struct BitVecFieldDescriptor {
 }
 
 pub fn format_flags_line(set: Vec<String>, cleared: Vec<String>) -> String {
-    if set.len() > 1 && cleared.len() == 0 {
+    if !set.is_empty() && cleared.is_empty() {
         set.join(" ")
-    } else if set.len() == 0 && cleared.len() > 1 {
+    } else if set.is_empty() && !cleared.is_empty() {
         cleared.join(" ")
     } else {
         let set_joined = set.join(" ");
@@ -19,3 +20,52 @@ pub fn format_flags_line(set: Vec<String>, cleared: Vec<String>) -> String {
         format!("{} {}", set_joined, cleared_joined)
     }
 }
+
+pub fn format_bitvec_flags(vector: u32, fields: &[BitVecFieldDescriptor]) -> String {
+    let mut offset = 0;
+    let mut fields_enabled: Vec<String> = vec![];
+    let mut fields_disabled: Vec<String> = vec![];
+
+    for desc in fields {
+        let enabled = (vector >> offset) & 0x1;
+
+        if desc.is_reserved {
+            offset += desc.len;
+            continue;
+        }
+
+        match enabled {
+            1 => {
+                let field = format!("+{}", desc.name);
+                fields_enabled.push(field);
+            }
+            _ => {
+                let field = format!("-{}", desc.name);
+                fields_disabled.push(field);
+            }
+        };
+
+        offset += desc.len;
+    }
+
+    format_flags_line(fields_enabled, fields_disabled)
+}
+
+// a register as a line of values followed by a line of flags, aligned under
+// the register name
+pub fn push_register_lines(name: &str, values: String, flags: String, lines: &mut Vec<String>) {
+    let prefix = format!("{:<9}: ", name);
+    match values.is_empty() {
+        true => lines.push(format!("{}{}", prefix, flags)),
+        false => {
+            lines.push(format!("{}{}", prefix, values));
+            lines.push(format!("{:<11}{}", "", flags));
+        }
+    }
+}
+
+// a name from one of the embedded ID tables, which are sorted by ID
+pub fn lookup_name<K: Ord>(table: &'static [(K, &'static str)], key: &K) -> Option<&'static str> {
+    let index = table.binary_search_by(|(id, _)| id.cmp(key)).ok()?;
+    Some(table[index].1)
+}
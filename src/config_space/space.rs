@@ -1,24 +1,82 @@
+use alloc::boxed::Box;
 use alloc::{format, vec};
 use alloc::{string::String, vec::Vec};
 
+use crate::config_space::bar::{BarDecoder, BarPrettyPrinter};
+use crate::config_space::bridge_control::{
+    BridgeControlPrettyPrinter, BridgeControlRegister, CardBusBridgeControlPrettyPrinter,
+    CardBusBridgeControlRegister,
+};
+use crate::config_space::bridge_window::{BridgeWindow, BridgeWindows};
+use crate::config_space::capabilities::{
+    capability_name, Capability, CapabilityError, CapabilityIter, CapabilityPrettyPrinter,
+    PciExpressCapability, CAP_ID_PCI_EXPRESS,
+};
 use crate::config_space::command::CommandPrettyPrinter;
-use crate::config_space::devices::DEVICE_MAP;
-use crate::config_space::header_type::{HeaderTypePrettyPrinter, HeaderTypeRegister};
+use crate::config_space::extended::{
+    extended_capability_name, ExtendedCapability, ExtendedCapabilityError, ExtendedCapabilityIter,
+    ExtendedCapabilityPrettyPrinter, EXTENDED_CAPABILITIES_START,
+};
+use crate::config_space::header_type::{
+    HeaderTypePrettyPrinter, HeaderTypeRegister, HEADER_LAYOUT_BRIDGE, HEADER_LAYOUT_CARDBUS,
+    HEADER_LAYOUT_GENERAL,
+};
+use crate::config_space::ids::PciIds;
+use crate::config_space::resource::ResourceSizes;
+use crate::config_space::secondary_status::{
+    SecondaryStatusPrettyPrinter, SecondaryStatusRegister,
+};
 use crate::config_space::status::{StatusPrettyPrinter, StatusRegister};
-use crate::config_space::vendors::VENDOR_MAP;
+use crate::config_space::vendor_specific::{
+    VendorCapabilityDecoder, VendorCapabilityKey, VendorCapabilityRegistry,
+};
 use crate::config_space::CommandRegister;
 
+const VENDOR_ID_OFFSET: usize = 0x00;
+const DEVICE_ID_OFFSET: usize = 0x02;
+const REVISION_ID_OFFSET: usize = 0x08;
+const PROG_IF_OFFSET: usize = 0x09;
+const SUBCLASS_OFFSET: usize = 0x0a;
+const CLASS_OFFSET: usize = 0x0b;
+const HEADER_TYPE_OFFSET: usize = 0x0e;
+const SUBSYSTEM_OFFSET_GENERAL: usize = 0x2c;
+const SUBSYSTEM_OFFSET_CARDBUS: usize = 0x40;
+
+// subsystem vendor and subsystem ID, only in the general and CardBus layouts
+fn subsystem_ids(cf: &ConfigSpace) -> Option<(u16, u16)> {
+    let header_type = HeaderTypeRegister::from(cf.byte(HEADER_TYPE_OFFSET));
+    let offset = match header_type.layout() {
+        HEADER_LAYOUT_GENERAL => SUBSYSTEM_OFFSET_GENERAL,
+        HEADER_LAYOUT_CARDBUS => SUBSYSTEM_OFFSET_CARDBUS,
+        _ => return None,
+    };
+    if offset + 4 > cf.len() {
+        return None;
+    }
+
+    Some((cf.word(offset), cf.word(offset + 2)))
+}
+
 #[derive(PartialEq, Eq)]
 enum FieldKind {
     VendorField,
     DeviceField,
+    SubsystemVendorField,
+    SubsystemField,
     BitField,
     CommandRegister,
     StatusRegister,
     HeaderTypeRegister,
+    SecondaryStatusRegister,
+    BridgeControlRegister,
+    CardBusBridgeControlRegister,
     AddressField,
     IntField,
-    IdField,
+    ProgIfField,
+    SubclassField,
+    ClassField,
+    InterruptPinField,
+    Reserved,
 }
 
 struct FieldDescriptor {
@@ -27,7 +85,7 @@ struct FieldDescriptor {
     kind: FieldKind,
 }
 
-const HEADER_TYPE_00: [FieldDescriptor; 12] = [
+const HEADER_COMMON: [FieldDescriptor; 12] = [
     FieldDescriptor {
         len: 2,
         name: "vendor_id",
@@ -56,17 +114,17 @@ const HEADER_TYPE_00: [FieldDescriptor; 12] = [
     FieldDescriptor {
         len: 1,
         name: "prog_if",
-        kind: FieldKind::IdField,
+        kind: FieldKind::ProgIfField,
     },
     FieldDescriptor {
         len: 1,
         name: "subclass",
-        kind: FieldKind::IdField,
+        kind: FieldKind::SubclassField,
     },
     FieldDescriptor {
         len: 1,
         name: "class",
-        kind: FieldKind::IdField,
+        kind: FieldKind::ClassField,
     },
     FieldDescriptor {
         len: 1,
@@ -90,8 +148,321 @@ const HEADER_TYPE_00: [FieldDescriptor; 12] = [
     },
 ];
 
+const HEADER_TYPE_00: [FieldDescriptor; 16] = [
+    FieldDescriptor {
+        len: 4,
+        name: "base_addr_0",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "base_addr_1",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "base_addr_2",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "base_addr_3",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "base_addr_4",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "base_addr_5",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "cardbus_cis_ptr",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "subsystem_vendor",
+        kind: FieldKind::SubsystemVendorField,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "subsystem_id",
+        kind: FieldKind::SubsystemField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "expansion_rom",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "capabilities_ptr",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 7,
+        name: "reserved",
+        kind: FieldKind::Reserved,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "interrupt_line",
+        kind: FieldKind::IntField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "interrupt_pin",
+        kind: FieldKind::InterruptPinField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "min_grant",
+        kind: FieldKind::IntField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "max_latency",
+        kind: FieldKind::IntField,
+    },
+];
+
+const HEADER_TYPE_01: [FieldDescriptor; 23] = [
+    FieldDescriptor {
+        len: 4,
+        name: "base_addr_0",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "base_addr_1",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "primary_bus",
+        kind: FieldKind::IntField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "secondary_bus",
+        kind: FieldKind::IntField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "subordinate_bus",
+        kind: FieldKind::IntField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "sec_latency_timer",
+        kind: FieldKind::IntField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "io_base",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "io_limit",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "secondary_status",
+        kind: FieldKind::SecondaryStatusRegister,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "memory_base",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "memory_limit",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "prefetch_base",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "prefetch_limit",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "prefetch_base_upper",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "prefetch_limit_upper",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "io_base_upper",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "io_limit_upper",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "capabilities_ptr",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 3,
+        name: "reserved",
+        kind: FieldKind::Reserved,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "expansion_rom",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "interrupt_line",
+        kind: FieldKind::IntField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "interrupt_pin",
+        kind: FieldKind::InterruptPinField,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "bridge_control",
+        kind: FieldKind::BridgeControlRegister,
+    },
+];
+
+const HEADER_TYPE_02: [FieldDescriptor; 22] = [
+    FieldDescriptor {
+        len: 4,
+        name: "socket_base",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "capabilities_ptr",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "reserved",
+        kind: FieldKind::Reserved,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "secondary_status",
+        kind: FieldKind::SecondaryStatusRegister,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "pci_bus",
+        kind: FieldKind::IntField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "cardbus_bus",
+        kind: FieldKind::IntField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "subordinate_bus",
+        kind: FieldKind::IntField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "cardbus_latency",
+        kind: FieldKind::IntField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "memory_base_0",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "memory_limit_0",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "memory_base_1",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "memory_limit_1",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "io_base_0",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "io_limit_0",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "io_base_1",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "io_limit_1",
+        kind: FieldKind::AddressField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "interrupt_line",
+        kind: FieldKind::IntField,
+    },
+    FieldDescriptor {
+        len: 1,
+        name: "interrupt_pin",
+        kind: FieldKind::InterruptPinField,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "bridge_control",
+        kind: FieldKind::CardBusBridgeControlRegister,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "subsystem_vendor",
+        kind: FieldKind::SubsystemVendorField,
+    },
+    FieldDescriptor {
+        len: 2,
+        name: "subsystem_id",
+        kind: FieldKind::SubsystemField,
+    },
+    FieldDescriptor {
+        len: 4,
+        name: "legacy_mode_base",
+        kind: FieldKind::AddressField,
+    },
+];
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-#[repr(packed)]
 pub struct ConfigSpace {
     slice: Vec<u8>,
     // // byte 0
@@ -152,6 +523,13 @@ pub struct ConfigSpace {
     // pub max_latency: u8,
 }
 
+/// Reasons a read from config space fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSpaceError {
+    // the read at this offset runs past the end of the readable config space
+    OutOfBounds(usize),
+}
+
 impl From<Vec<u8>> for ConfigSpace {
     // fn from(bytes: Vec<u8>) -> Self {
     //     assert!(bytes.len() >= 64);
@@ -169,11 +547,149 @@ impl From<Vec<u8>> for ConfigSpace {
     }
 }
 
-pub struct ConfigSpacePrettyPrinter {}
+impl ConfigSpace {
+    /// Iterates over the standard capability list, if the device has one.
+    pub fn capabilities(&self) -> CapabilityIter<'_> {
+        CapabilityIter::new(self)
+    }
+
+    /// Iterates over the PCI Express extended capability list, if the
+    /// extended config space was readable.
+    pub fn extended_capabilities(&self) -> ExtendedCapabilityIter<'_> {
+        ExtendedCapabilityIter::new(self)
+    }
+
+    /// Finds the first standard capability with the given ID.
+    pub fn find_capability(&self, id: u8) -> Option<Capability> {
+        self.capabilities()
+            .filter_map(Result::ok)
+            .find(|cap| cap.id == id)
+    }
+
+    /// Finds the first extended capability with the given ID.
+    pub fn find_extended_capability(&self, id: u16) -> Option<ExtendedCapability> {
+        self.extended_capabilities()
+            .filter_map(Result::ok)
+            .find(|cap| cap.id == id)
+    }
+
+    /// Reads the PCI Express capability, which tells what kind of port or
+    /// endpoint the function is.
+    pub fn pci_express(&self) -> Option<PciExpressCapability> {
+        let cap = self.find_capability(CAP_ID_PCI_EXPRESS)?;
+        PciExpressCapability::read(self, cap.offset)
+    }
+
+    pub fn vendor_id(&self) -> u16 {
+        self.word(VENDOR_ID_OFFSET)
+    }
+
+    pub fn device_id(&self) -> u16 {
+        self.word(DEVICE_ID_OFFSET)
+    }
+
+    pub fn revision_id(&self) -> u8 {
+        self.byte(REVISION_ID_OFFSET)
+    }
+
+    /// The class, subclass and programming interface as one 24-bit value,
+    /// as in 0x0c0330 for an XHCI controller.
+    pub fn class_code(&self) -> u32 {
+        (self.byte(CLASS_OFFSET) as u32) << 16
+            | (self.byte(SUBCLASS_OFFSET) as u32) << 8
+            | (self.byte(PROG_IF_OFFSET) as u32)
+    }
+
+    /// The header type register: the layout in bits 6:0 and the
+    /// multi-function flag in bit 7.
+    pub fn header_type(&self) -> u8 {
+        self.byte(HEADER_TYPE_OFFSET)
+    }
+
+    /// Reads a byte at any offset in the readable config space.
+    pub fn read_u8(&self, offset: usize) -> Result<u8, ConfigSpaceError> {
+        Ok(u8::from_le_bytes(self.read_bytes(offset)?))
+    }
+
+    /// Reads a little-endian word at any offset, aligned or not.
+    pub fn read_u16(&self, offset: usize) -> Result<u16, ConfigSpaceError> {
+        Ok(u16::from_le_bytes(self.read_bytes(offset)?))
+    }
+
+    /// Reads a little-endian dword at any offset, aligned or not.
+    pub fn read_u32(&self, offset: usize) -> Result<u32, ConfigSpaceError> {
+        Ok(u32::from_le_bytes(self.read_bytes(offset)?))
+    }
+
+    fn read_bytes<const N: usize>(&self, offset: usize) -> Result<[u8; N], ConfigSpaceError> {
+        let mut bytes = [0; N];
+        let slice = self
+            .slice(offset, N)
+            .ok_or(ConfigSpaceError::OutOfBounds(offset))?;
+        bytes.copy_from_slice(slice);
+        Ok(bytes)
+    }
+
+    pub(crate) fn slice(&self, offset: usize, len: usize) -> Option<&[u8]> {
+        self.slice.get(offset..offset.checked_add(len)?)
+    }
+
+    /// The number of bytes read, which is only the 64 byte header for
+    /// users other than root.
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    pub(crate) fn byte(&self, offset: usize) -> u8 {
+        self.slice[offset]
+    }
+
+    pub(crate) fn word(&self, offset: usize) -> u16 {
+        (self.slice[offset + 1] as u16) << 8 | (self.slice[offset] as u16)
+    }
+
+    pub(crate) fn dword(&self, offset: usize) -> u32 {
+        (self.word(offset + 2) as u32) << 16 | (self.word(offset) as u32)
+    }
+}
+
+pub struct ConfigSpacePrettyPrinter {
+    vendor_decoders: VendorCapabilityRegistry,
+    ids: PciIds,
+}
+
+impl Default for ConfigSpacePrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl ConfigSpacePrettyPrinter {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            vendor_decoders: VendorCapabilityRegistry::new(),
+            ids: PciIds::new(),
+        }
+    }
+
+    /// Uses names from a pci.ids file loaded at runtime and any local
+    /// overrides, with the embedded tables as the fallback.
+    pub fn set_ids(&mut self, ids: PciIds) {
+        self.ids = ids;
+    }
+
+    /// Adds a decoder for a vendor-specific capability. Its output is shown
+    /// under the capability it matches.
+    pub fn register_vendor_decoder(
+        &mut self,
+        key: VendorCapabilityKey,
+        decoder: Box<dyn VendorCapabilityDecoder>,
+    ) {
+        self.vendor_decoders.register(key, decoder);
     }
 
     fn assemble_u16(&self, val: &[u8]) -> u16 {
@@ -183,14 +699,28 @@ impl ConfigSpacePrettyPrinter {
 
     fn assemble_u32(&self, val: &[u8]) -> u32 {
         assert_eq!(4, val.len());
-        (val[3] as u32) << 24 | (val[0] as u32)
+        (val[3] as u32) << 24 | (val[2] as u32) << 16 | (val[1] as u32) << 8 | (val[0] as u32)
     }
 
-    fn print_value(&self, desc: &FieldDescriptor, slice: &[u8], vendor_id: u16) -> String {
+    fn print_value(
+        &self,
+        desc: &FieldDescriptor,
+        slice: &[u8],
+        vendor_id: u16,
+        cf: &ConfigSpace,
+    ) -> String {
         match desc.kind {
             FieldKind::VendorField => {
                 let id = self.assemble_u16(slice);
-                let name = VENDOR_MAP.get(&id);
+                let name = self.ids.vendor(id);
+                match name {
+                    Some(name) => format!("{} [0x{:04x}]", name, id),
+                    None => format!("0x{:04x}", id),
+                }
+            }
+            FieldKind::SubsystemVendorField => {
+                let id = self.assemble_u16(slice);
+                let name = self.ids.vendor(id);
                 match name {
                     Some(name) => format!("{} [0x{:04x}]", name, id),
                     None => format!("0x{:04x}", id),
@@ -198,7 +728,42 @@ impl ConfigSpacePrettyPrinter {
             }
             FieldKind::DeviceField => {
                 let id = self.assemble_u16(slice);
-                let name = DEVICE_MAP.get(&(vendor_id, id));
+                let name = self.ids.device(vendor_id, id);
+                match name {
+                    Some(name) => format!("{} [0x{:04x}]", name, id),
+                    None => format!("0x{:04x}", id),
+                }
+            }
+            FieldKind::ProgIfField => {
+                let value = slice[0];
+                let name = self
+                    .ids
+                    .prog_if(cf.byte(CLASS_OFFSET), cf.byte(SUBCLASS_OFFSET), value);
+                match name {
+                    Some(name) => format!("{} [0x{:02x}]", name, value),
+                    None => format!("0x{:02x}", value),
+                }
+            }
+            FieldKind::SubclassField => {
+                let value = slice[0];
+                match self.ids.subclass(cf.byte(CLASS_OFFSET), value) {
+                    Some(name) => format!("{} [0x{:02x}]", name, value),
+                    None => format!("0x{:02x}", value),
+                }
+            }
+            FieldKind::ClassField => {
+                let value = slice[0];
+                match self.ids.class(value) {
+                    Some(name) => format!("{} [0x{:02x}]", name, value),
+                    None => format!("0x{:02x}", value),
+                }
+            }
+            FieldKind::SubsystemField => {
+                let id = self.assemble_u16(slice);
+                let name = subsystem_ids(cf).and_then(|(subvendor_id, _)| {
+                    self.ids
+                        .subsystem(vendor_id, cf.device_id(), subvendor_id, id)
+                });
                 match name {
                     Some(name) => format!("{} [0x{:04x}]", name, id),
                     None => format!("0x{:04x}", id),
@@ -206,18 +771,39 @@ impl ConfigSpacePrettyPrinter {
             }
             FieldKind::CommandRegister => {
                 let value = self.assemble_u16(slice);
-                let reg = CommandRegister::from(value as u16);
+                let reg = CommandRegister::from(value);
                 let printer = CommandPrettyPrinter::new();
                 let flags = printer.print(&reg);
                 format!("{} [0x{:04x}]", flags, value)
             }
             FieldKind::StatusRegister => {
                 let value = self.assemble_u16(slice);
-                let reg = StatusRegister::from(value as u16);
+                let reg = StatusRegister::from(value);
                 let printer = StatusPrettyPrinter::new();
                 let flags = printer.print(&reg);
                 format!("{} [0x{:04x}]", flags, value)
             }
+            FieldKind::SecondaryStatusRegister => {
+                let value = self.assemble_u16(slice);
+                let reg = SecondaryStatusRegister::from(value);
+                let printer = SecondaryStatusPrettyPrinter::new();
+                let flags = printer.print(&reg);
+                format!("{} [0x{:04x}]", flags, value)
+            }
+            FieldKind::BridgeControlRegister => {
+                let value = self.assemble_u16(slice);
+                let reg = BridgeControlRegister::from(value);
+                let printer = BridgeControlPrettyPrinter::new();
+                let flags = printer.print(&reg);
+                format!("{} [0x{:04x}]", flags, value)
+            }
+            FieldKind::CardBusBridgeControlRegister => {
+                let value = self.assemble_u16(slice);
+                let reg = CardBusBridgeControlRegister::from(value);
+                let printer = CardBusBridgeControlPrettyPrinter::new();
+                let flags = printer.print(&reg);
+                format!("{} [0x{:04x}]", flags, value)
+            }
             FieldKind::HeaderTypeRegister => {
                 let value = slice[0];
                 let reg = HeaderTypeRegister::from(value);
@@ -225,6 +811,14 @@ impl ConfigSpacePrettyPrinter {
                 let flags = printer.print(&reg);
                 format!("{} [0x{:02x}]", flags, value)
             }
+            FieldKind::InterruptPinField => {
+                let value = slice[0];
+                match value {
+                    0 => format!("none [0x{:02x}]", value),
+                    1..=4 => format!("INT{}# [0x{:02x}]", (b'A' + value - 1) as char, value),
+                    _ => format!("invalid [0x{:02x}]", value),
+                }
+            }
             _ => match desc.len {
                 1 => {
                     let value = slice[0];
@@ -238,33 +832,294 @@ impl ConfigSpacePrettyPrinter {
                     let value = self.assemble_u32(slice);
                     format!("0x{:08x}", value)
                 }
-                _ => format!(""),
+                _ => String::new(),
             },
         }
     }
 
-    pub fn print(&self, cf: &ConfigSpace) -> String {
-        let mut offset = 0;
-        let mut lines: Vec<String> = vec![];
-        let mut vendor_id = 0;
+    fn print_fields(
+        &self,
+        descs: &[FieldDescriptor],
+        start: usize,
+        cf: &ConfigSpace,
+        vendor_id: &mut u16,
+        lines: &mut Vec<String>,
+    ) {
+        let mut offset = start;
 
-        for desc in HEADER_TYPE_00 {
+        for desc in descs {
             let low = offset;
             let high = offset + desc.len;
+
+            // the cardbus layout runs past the 64 bytes every device is
+            // guaranteed to expose
+            if high > cf.slice.len() {
+                let line = format!("{:<20}: [unavailable]\n", desc.name);
+                lines.push(line);
+                offset += desc.len;
+                continue;
+            }
+
             let slice = &cf.slice[low..high];
 
             if desc.kind == FieldKind::VendorField {
-                vendor_id = self.assemble_u16(slice);
+                *vendor_id = self.assemble_u16(slice);
             }
 
-            let value_fmt = self.print_value(&desc, slice, vendor_id);
+            if desc.kind == FieldKind::Reserved {
+                offset += desc.len;
+                continue;
+            }
+
+            let value_fmt = self.print_value(desc, slice, *vendor_id, cf);
 
             let line = format!("{:<20}: {}\n", desc.name, value_fmt);
             lines.push(line);
 
             offset += desc.len;
         }
+    }
+
+    fn print_window(
+        &self,
+        name: &str,
+        window: &BridgeWindow,
+        width: usize,
+        lines: &mut Vec<String>,
+    ) {
+        let range = match window.is_enabled() {
+            true => format!("0x{:0w$x}-0x{:0w$x}", window.base, window.limit, w = width),
+            false => String::from("[disabled]"),
+        };
+        let line = format!("{:<20}: {}\n", name, range);
+        lines.push(line);
+    }
+
+    fn print_bridge_windows(&self, cf: &ConfigSpace, lines: &mut Vec<String>) {
+        let windows = BridgeWindows::from(cf);
+
+        let io_width = if windows.io.is_wide { 8 } else { 4 };
+        let prefetch_width = if windows.prefetchable.is_wide { 16 } else { 8 };
+
+        self.print_window("io_window", &windows.io, io_width, lines);
+        self.print_window("memory_window", &windows.memory, 8, lines);
+        self.print_window(
+            "prefetch_window",
+            &windows.prefetchable,
+            prefetch_width,
+            lines,
+        );
+    }
+
+    fn print_regions(&self, cf: &ConfigSpace, sizes: &ResourceSizes, lines: &mut Vec<String>) {
+        let decoder = BarDecoder::new();
+        let printer = BarPrettyPrinter::new();
+
+        for bar in decoder.decode(cf, sizes) {
+            let name = format!("BAR{}", bar.index);
+            let line = format!("{:<20}: {}\n", name, printer.print(&bar));
+            lines.push(line);
+        }
+
+        if let Some(rom) = decoder.decode_rom(cf, sizes) {
+            let line = format!("{:<20}: {}\n", "ROM", printer.print_rom(&rom));
+            lines.push(line);
+        }
+    }
+
+    fn print_capabilities(&self, cf: &ConfigSpace, lines: &mut Vec<String>) {
+        let printer = CapabilityPrettyPrinter::new();
+
+        for result in cf.capabilities() {
+            let line = match result {
+                Ok(cap) => {
+                    let name = format!("capability [{:02x}]", cap.offset);
+                    let cap_name = capability_name(cap.id).unwrap_or("Unknown");
+                    lines.push(format!("{:<20}: {} [0x{:02x}]\n", name, cap_name, cap.id));
+
+                    for detail in printer.print(cf, &cap, &self.vendor_decoders) {
+                        lines.push(format!("    {}\n", detail));
+                    }
+                    continue;
+                }
+                Err(CapabilityError::OutOfBounds(offset)) if cf.len() <= offset as usize => {
+                    format!(
+                        "capabilities not visible, only {} bytes of config space readable\n",
+                        cf.len()
+                    )
+                }
+                Err(CapabilityError::OutOfBounds(offset)) => {
+                    format!(
+                        "capability list broken: pointer 0x{:02x} out of bounds\n",
+                        offset
+                    )
+                }
+                Err(CapabilityError::Misaligned(offset)) => {
+                    format!(
+                        "capability list broken: pointer 0x{:02x} misaligned\n",
+                        offset
+                    )
+                }
+                Err(CapabilityError::Loop(offset)) => {
+                    format!("capability list broken: pointer 0x{:02x} loops\n", offset)
+                }
+            };
+            lines.push(line);
+        }
+    }
+
+    fn print_extended_capabilities(&self, cf: &ConfigSpace, lines: &mut Vec<String>) {
+        if cf.len() <= EXTENDED_CAPABILITIES_START {
+            let line = format!(
+                "extended capabilities not visible, only {} bytes of config space readable\n",
+                cf.len()
+            );
+            lines.push(line);
+            return;
+        }
+
+        let printer = ExtendedCapabilityPrettyPrinter::new();
+
+        for result in cf.extended_capabilities() {
+            let line = match result {
+                Ok(cap) => {
+                    let name = format!("ext capability [{:03x}]", cap.offset);
+                    let cap_name = extended_capability_name(cap.id).unwrap_or("Unknown");
+                    lines.push(format!(
+                        "{:<20}: {} [0x{:04x}] v{}\n",
+                        name, cap_name, cap.id, cap.version
+                    ));
+
+                    for detail in printer.print(cf, &cap, &self.vendor_decoders) {
+                        lines.push(format!("    {}\n", detail));
+                    }
+                    continue;
+                }
+                Err(ExtendedCapabilityError::OutOfBounds(offset)) => {
+                    format!(
+                        "extended capability list broken: pointer 0x{:03x} out of bounds\n",
+                        offset
+                    )
+                }
+                Err(ExtendedCapabilityError::Misaligned(offset)) => {
+                    format!(
+                        "extended capability list broken: pointer 0x{:03x} misaligned\n",
+                        offset
+                    )
+                }
+                Err(ExtendedCapabilityError::Loop(offset)) => {
+                    format!(
+                        "extended capability list broken: pointer 0x{:03x} loops\n",
+                        offset
+                    )
+                }
+            };
+            lines.push(line);
+        }
+    }
+
+    /// The class of the device, as in "Network controller / Ethernet
+    /// controller".
+    pub fn print_class(&self, cf: &ConfigSpace) -> String {
+        self.ids.describe_class(
+            cf.byte(CLASS_OFFSET),
+            cf.byte(SUBCLASS_OFFSET),
+            cf.byte(PROG_IF_OFFSET),
+        )
+    }
+
+    /// The subsystem of the device like lspci's "Subsystem:" line, or `None`
+    /// for bridges and devices that don't set one.
+    pub fn print_subsystem(&self, cf: &ConfigSpace) -> Option<String> {
+        let (subvendor_id, subdevice_id) = subsystem_ids(cf)?;
+        if subvendor_id == 0x0000 || subvendor_id == 0xffff {
+            return None;
+        }
+
+        Some(self.ids.describe_subsystem(
+            cf.vendor_id(),
+            cf.device_id(),
+            subvendor_id,
+            subdevice_id,
+        ))
+    }
+
+    pub fn print(&self, cf: &ConfigSpace) -> String {
+        self.print_with_resources(cf, &ResourceSizes::new())
+    }
+
+    /// Like `print`, but also reports region sizes, which have to come from
+    /// outside config space (sysfs `resource` or `/proc/bus/pci/devices`).
+    pub fn print_with_resources(&self, cf: &ConfigSpace, sizes: &ResourceSizes) -> String {
+        let mut lines: Vec<String> = vec![];
+        let mut vendor_id = 0;
+
+        self.print_fields(&HEADER_COMMON, 0x00, cf, &mut vendor_id, &mut lines);
+
+        let header_type = HeaderTypeRegister::from(cf.byte(HEADER_TYPE_OFFSET));
+        match header_type.layout() {
+            HEADER_LAYOUT_GENERAL => {
+                self.print_fields(&HEADER_TYPE_00, 0x10, cf, &mut vendor_id, &mut lines);
+                self.print_regions(cf, sizes, &mut lines);
+            }
+            HEADER_LAYOUT_BRIDGE => {
+                self.print_fields(&HEADER_TYPE_01, 0x10, cf, &mut vendor_id, &mut lines);
+                self.print_regions(cf, sizes, &mut lines);
+                self.print_bridge_windows(cf, &mut lines);
+            }
+            HEADER_LAYOUT_CARDBUS => {
+                self.print_fields(&HEADER_TYPE_02, 0x10, cf, &mut vendor_id, &mut lines);
+            }
+            layout => {
+                let line = format!(
+                    "unknown header layout 0x{:02x}, fields past 0x0f not decoded\n",
+                    layout
+                );
+                lines.push(line);
+                return lines.join("");
+            }
+        }
+
+        self.print_capabilities(cf, &mut lines);
+        self.print_extended_capabilities(cf, &mut lines);
 
         lines.join("")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_space() -> ConfigSpace {
+        ConfigSpace::from((0..64).collect::<Vec<u8>>())
+    }
+
+    #[test]
+    fn reads_little_endian_at_any_offset() {
+        let cf = config_space();
+        assert_eq!(cf.read_u8(0x05), Ok(0x05));
+        assert_eq!(cf.read_u16(0x05), Ok(0x0605));
+        assert_eq!(cf.read_u32(0x05), Ok(0x0807_0605));
+    }
+
+    #[test]
+    fn reads_up_to_the_end() {
+        let cf = config_space();
+        assert_eq!(cf.read_u8(0x3f), Ok(0x3f));
+        assert_eq!(cf.read_u16(0x3e), Ok(0x3f3e));
+        assert_eq!(cf.read_u32(0x3c), Ok(0x3f3e_3d3c));
+    }
+
+    #[test]
+    fn fails_past_the_end() {
+        let cf = config_space();
+        assert_eq!(cf.read_u8(0x40), Err(ConfigSpaceError::OutOfBounds(0x40)));
+        assert_eq!(cf.read_u16(0x3f), Err(ConfigSpaceError::OutOfBounds(0x3f)));
+        assert_eq!(cf.read_u32(0x3d), Err(ConfigSpaceError::OutOfBounds(0x3d)));
+        assert_eq!(
+            cf.read_u32(usize::MAX),
+            Err(ConfigSpaceError::OutOfBounds(usize::MAX))
+        );
+    }
+}
@@ -1,9 +1,14 @@
-use alloc::vec;
-use alloc::{format, string::String, vec::Vec};
+use alloc::{format, string::String};
 
-use crate::config_space::shared::format_flags_line;
+use crate::config_space::shared::{format_bitvec_flags, BitVecFieldDescriptor};
 
-use super::shared::BitVecFieldDescriptor;
+// how quickly the device claims a transaction, as lspci names it
+const DEVSEL_TIMINGS: [&str; 4] = ["fast", "medium", "slow", "??"];
+
+// bits 10:9 of the status and secondary status registers
+pub fn devsel_name(vector: u16) -> &'static str {
+    DEVSEL_TIMINGS[((vector >> 9) & 0x3) as usize]
+}
 
 const STATUS_FIELDS: [BitVecFieldDescriptor; 13] = [
     BitVecFieldDescriptor {
@@ -47,11 +52,11 @@ const STATUS_FIELDS: [BitVecFieldDescriptor; 13] = [
         name: "MasterDataParErr",
         is_reserved: false,
     },
-    // DEVSEL Timing
+    // DEVSEL Timing, a 2-bit field printed separately
     BitVecFieldDescriptor {
-        len: 1,
+        len: 2,
         name: "DEVSEL",
-        is_reserved: false,
+        is_reserved: true,
     },
     // Signaled Target Abort
     BitVecFieldDescriptor {
@@ -95,6 +100,12 @@ impl From<u16> for StatusRegister {
     }
 }
 
+impl StatusRegister {
+    pub fn has_capabilities(&self) -> bool {
+        self.vector & 0x10 > 0
+    }
+}
+
 pub struct StatusPrettyPrinter {}
 
 impl StatusPrettyPrinter {
@@ -103,32 +114,36 @@ impl StatusPrettyPrinter {
     }
 
     pub fn print(&self, cmd: &StatusRegister) -> String {
-        let mut offset = 0;
-        let mut fields_enabled: Vec<String> = vec![];
-        let mut fields_disabled: Vec<String> = vec![];
-
-        for desc in STATUS_FIELDS {
-            let enabled = (cmd.vector >> offset) & 0x1;
+        let flags = format_bitvec_flags(cmd.vector as u32, &STATUS_FIELDS);
+        format!("{} DEVSEL={}", flags, devsel_name(cmd.vector))
+    }
+}
 
-            if desc.is_reserved {
-                offset += desc.len;
-                continue;
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-            match enabled {
-                1 => {
-                    let field = format!("+{}", desc.name);
-                    fields_enabled.push(field);
-                }
-                _ => {
-                    let field = format!("-{}", desc.name);
-                    fields_disabled.push(field);
-                }
-            };
+    fn print(value: u16) -> String {
+        StatusPrettyPrinter::new().print(&StatusRegister::from(value))
+    }
 
-            offset += desc.len;
-        }
+    #[test]
+    fn decodes_the_devsel_timing() {
+        assert!(print(0x0000).ends_with(" DEVSEL=fast"));
+        assert!(print(0x0200).ends_with(" DEVSEL=medium"));
+        assert!(print(0x0400).ends_with(" DEVSEL=slow"));
+        assert!(print(0x0600).ends_with(" DEVSEL=??"));
+    }
 
-        format_flags_line(fields_enabled, fields_disabled)
+    #[test]
+    fn keeps_devsel_out_of_the_flags() {
+        // Cap and FastB2B with medium timing
+        assert_eq!(
+            print(0x0290),
+            "+Cap +FastB2B -INTx -66MHz -MasterDataParErr -SigTAbrt -RecvTAbrt \
+             -RecvMAbrt -SigSysErr -ParErr DEVSEL=medium"
+        );
+        // the flags after DEVSEL stay in place
+        assert!(print(0x0800).starts_with("+SigTAbrt "));
     }
 }
@@ -0,0 +1,28 @@
+use alloc::string::String;
+
+use crate::config_space::database::tables;
+use crate::config_space::ids::PciIds;
+use crate::config_space::shared::lookup_name;
+
+pub fn subsystem_name(
+    vendor_id: u16,
+    device_id: u16,
+    subvendor_id: u16,
+    subdevice_id: u16,
+) -> Option<&'static str> {
+    lookup_name(
+        tables().subsystems,
+        &(vendor_id, device_id, subvendor_id, subdevice_id),
+    )
+}
+
+/// Describes a subsystem the way lspci's "Subsystem:" line does, from the
+/// embedded tables only.
+pub fn describe_subsystem(
+    vendor_id: u16,
+    device_id: u16,
+    subvendor_id: u16,
+    subdevice_id: u16,
+) -> String {
+    PciIds::new().describe_subsystem(vendor_id, device_id, subvendor_id, subdevice_id)
+}
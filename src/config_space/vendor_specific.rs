@@ -0,0 +1,184 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+use crate::config_space::ConfigSpace;
+
+const VENDOR_ID_OFFSET: usize = 0x00;
+
+// standard vendor specific capability: byte 2 holds the length
+const VENDOR_LENGTH: usize = 0x02;
+const VENDOR_HEADER_LEN: usize = 0x03;
+
+// extended VSEC: VSEC ID, revision and length in the dword after the header
+const VSEC_HEADER: usize = 0x04;
+const VSEC_HEADER_LEN: usize = 0x08;
+
+// DVSEC: vendor, revision and length, then the DVSEC ID
+const DVSEC_HEADER_1: usize = 0x04;
+const DVSEC_HEADER_2: usize = 0x08;
+const DVSEC_HEADER_LEN: usize = 0x0a;
+
+/// Identifies which decoder handles a vendor-specific capability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VendorCapabilityKey {
+    // standard capability 0x09, which carries no ID of its own
+    Vendor { vendor_id: u16 },
+    // extended capability 0x000b, defined by the function's vendor
+    Vsec { vendor_id: u16, vsec_id: u16 },
+    // extended capability 0x0023, defined by the vendor named in it
+    Dvsec { vendor_id: u16, dvsec_id: u16 },
+}
+
+/// A vendor-specific capability handed to a decoder. `data` covers the
+/// whole capability, starting at its header, so offsets match vendor docs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VendorCapability<'a> {
+    pub key: VendorCapabilityKey,
+    pub offset: u16,
+    pub revision: u8,
+    pub length: u16,
+    pub data: &'a [u8],
+}
+
+impl<'a> VendorCapability<'a> {
+    /// Reads a standard vendor specific capability (ID 0x09).
+    pub fn read_vendor(cf: &'a ConfigSpace, offset: u8) -> Option<Self> {
+        let base = offset as usize;
+        if base + VENDOR_HEADER_LEN > cf.len() {
+            return None;
+        }
+
+        let length = cf.byte(base + VENDOR_LENGTH) as u16;
+        Some(Self {
+            key: VendorCapabilityKey::Vendor {
+                vendor_id: cf.word(VENDOR_ID_OFFSET),
+            },
+            offset: offset as u16,
+            revision: 0,
+            length,
+            data: cf.slice(base, length as usize)?,
+        })
+    }
+
+    /// Reads a vendor-specific extended capability (ID 0x000b).
+    pub fn read_vsec(cf: &'a ConfigSpace, offset: u16) -> Option<Self> {
+        let base = offset as usize;
+        if base + VSEC_HEADER_LEN > cf.len() {
+            return None;
+        }
+
+        let header = cf.dword(base + VSEC_HEADER);
+        let length = (header >> 20) as u16;
+        Some(Self {
+            key: VendorCapabilityKey::Vsec {
+                vendor_id: cf.word(VENDOR_ID_OFFSET),
+                vsec_id: (header & 0xffff) as u16,
+            },
+            offset,
+            revision: ((header >> 16) & 0xf) as u8,
+            length,
+            data: cf.slice(base, length as usize)?,
+        })
+    }
+
+    /// Reads a designated vendor-specific extended capability (ID 0x0023).
+    pub fn read_dvsec(cf: &'a ConfigSpace, offset: u16) -> Option<Self> {
+        let base = offset as usize;
+        if base + DVSEC_HEADER_LEN > cf.len() {
+            return None;
+        }
+
+        let header = cf.dword(base + DVSEC_HEADER_1);
+        let length = (header >> 20) as u16;
+        Some(Self {
+            key: VendorCapabilityKey::Dvsec {
+                vendor_id: (header & 0xffff) as u16,
+                dvsec_id: cf.word(base + DVSEC_HEADER_2),
+            },
+            offset,
+            revision: ((header >> 16) & 0xf) as u8,
+            length,
+            data: cf.slice(base, length as usize)?,
+        })
+    }
+}
+
+/// Decodes the body of a vendor-specific capability into printable lines.
+/// Register implementations with a `VendorCapabilityRegistry`.
+pub trait VendorCapabilityDecoder {
+    fn decode(&self, cap: &VendorCapability) -> Vec<String>;
+}
+
+/// Decoders for vendor-specific capabilities, keyed by vendor and VSEC or
+/// DVSEC ID.
+#[derive(Default)]
+pub struct VendorCapabilityRegistry {
+    decoders: BTreeMap<VendorCapabilityKey, Box<dyn VendorCapabilityDecoder>>,
+}
+
+impl VendorCapabilityRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a decoder, replacing any earlier one for the same key.
+    pub fn register(
+        &mut self,
+        key: VendorCapabilityKey,
+        decoder: Box<dyn VendorCapabilityDecoder>,
+    ) {
+        self.decoders.insert(key, decoder);
+    }
+
+    pub fn get(&self, key: &VendorCapabilityKey) -> Option<&dyn VendorCapabilityDecoder> {
+        self.decoders.get(key).map(|decoder| decoder.as_ref())
+    }
+}
+
+pub struct VendorCapabilityPrettyPrinter {}
+
+impl Default for VendorCapabilityPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VendorCapabilityPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn print(
+        &self,
+        cap: Option<VendorCapability>,
+        registry: &VendorCapabilityRegistry,
+    ) -> Vec<String> {
+        let cap = match cap {
+            Some(cap) => cap,
+            None => return vec![String::from("[truncated]")],
+        };
+
+        let header = match cap.key {
+            VendorCapabilityKey::Vendor { .. } => format!("Len {}", cap.length),
+            VendorCapabilityKey::Vsec { vsec_id, .. } => format!(
+                "VSEC ID 0x{:04x}, Rev {}, Len {}",
+                vsec_id, cap.revision, cap.length
+            ),
+            VendorCapabilityKey::Dvsec {
+                vendor_id,
+                dvsec_id,
+            } => format!(
+                "DVSEC Vendor 0x{:04x}, ID 0x{:04x}, Rev {}, Len {}",
+                vendor_id, dvsec_id, cap.revision, cap.length
+            ),
+        };
+
+        let mut lines = vec![header];
+        if let Some(decoder) = registry.get(&cap.key) {
+            lines.extend(decoder.decode(&cap));
+        }
+        lines
+    }
+}
@@ -0,0 +1,340 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::{format, string::String, vec::Vec};
+
+// resource tag names, large tags have bit 7 set and a 16-bit length
+const TAG_LARGE: u8 = 0x80;
+const TAG_IDENTIFIER: u8 = 0x02;
+const TAG_VPD_R: u8 = 0x10;
+const TAG_VPD_W: u8 = 0x11;
+const TAG_END: u8 = 0x0f;
+
+const KEYWORD_CHECKSUM: &str = "RV";
+const KEYWORD_READ_WRITE: &str = "RW";
+
+const KEYWORD_NAMES: [(&str, &str); 11] = [
+    ("CP", "Extended capability"),
+    ("EC", "Engineering changes"),
+    ("FG", "Fabric geography"),
+    ("LC", "Location"),
+    ("MN", "Manufacture ID"),
+    ("PG", "PCI geography"),
+    ("PN", "Part number"),
+    ("RV", "Reserved"),
+    ("RW", "Read-write area"),
+    ("SN", "Serial number"),
+    ("YA", "Asset tag"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VpdError {
+    // a tag or keyword runs past the end of the data, at this offset
+    Truncated(usize),
+    // the data ends without an end tag
+    MissingEnd,
+}
+
+/// The contents of a device's Vital Product Data, keyed by the two letter
+/// keywords of the read-only (VPD-R) and read-write (VPD-W) sections.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VitalProductData {
+    pub identifier: Option<String>,
+    pub read_only: BTreeMap<String, Vec<u8>>,
+    pub read_write: BTreeMap<String, Vec<u8>>,
+    // whether the bytes up to and including the RV checksum sum to zero,
+    // None without an RV keyword
+    pub checksum_valid: Option<bool>,
+}
+
+impl VitalProductData {
+    /// Parses VPD as read from the `vpd` file in sysfs. Anything after the
+    /// end tag is ignored, as devices often pad their VPD with garbage.
+    pub fn parse(bytes: &[u8]) -> Result<Self, VpdError> {
+        let mut vpd = Self::default();
+        let mut position = 0;
+
+        while position < bytes.len() {
+            let tag = bytes[position];
+
+            let (name, start, len) = match tag & TAG_LARGE > 0 {
+                true => {
+                    if position + 3 > bytes.len() {
+                        return Err(VpdError::Truncated(position));
+                    }
+                    let len = u16::from_le_bytes([bytes[position + 1], bytes[position + 2]]);
+                    (tag & 0x7f, position + 3, len as usize)
+                }
+                false => ((tag >> 3) & 0xf, position + 1, (tag & 0x7) as usize),
+            };
+
+            if name == TAG_END && tag & TAG_LARGE == 0 {
+                return Ok(vpd);
+            }
+            if start + len > bytes.len() {
+                return Err(VpdError::Truncated(position));
+            }
+            let data = &bytes[start..start + len];
+
+            match (tag & TAG_LARGE > 0, name) {
+                (true, TAG_IDENTIFIER) => {
+                    vpd.identifier = Some(String::from_utf8_lossy(data).into_owned());
+                }
+                (true, TAG_VPD_R) => {
+                    let (keywords, checksum) = parse_keywords(data, start)?;
+                    vpd.read_only = keywords;
+                    // all bytes up to and including the checksum sum to zero
+                    if let Some(end) = checksum {
+                        let sum = bytes[..=end]
+                            .iter()
+                            .fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+                        vpd.checksum_valid = Some(sum == 0);
+                    }
+                }
+                (true, TAG_VPD_W) => {
+                    vpd.read_write = parse_keywords(data, start)?.0;
+                }
+                // other tags are skipped
+                _ => {}
+            }
+
+            position = start + len;
+        }
+
+        Err(VpdError::MissingEnd)
+    }
+
+    /// The value of a keyword from either section, as text.
+    pub fn get_str(&self, keyword: &str) -> Option<String> {
+        let value = self
+            .read_only
+            .get(keyword)
+            .or_else(|| self.read_write.get(keyword))?;
+        Some(String::from_utf8_lossy(value).into_owned())
+    }
+
+    pub fn part_number(&self) -> Option<String> {
+        self.get_str("PN")
+    }
+
+    pub fn serial_number(&self) -> Option<String> {
+        self.get_str("SN")
+    }
+}
+
+type KeywordsWithChecksum = (BTreeMap<String, Vec<u8>>, Option<usize>);
+
+// a run of keywords: two characters, a length byte and the data. Also gives
+// the offset of the RV checksum byte, the first byte of its data.
+fn parse_keywords(data: &[u8], start: usize) -> Result<KeywordsWithChecksum, VpdError> {
+    let mut keywords = BTreeMap::new();
+    let mut checksum = None;
+    let mut position = 0;
+
+    while position < data.len() {
+        if position + 3 > data.len() {
+            return Err(VpdError::Truncated(start + position));
+        }
+        let keyword = String::from_utf8_lossy(&data[position..position + 2]).into_owned();
+        let len = data[position + 2] as usize;
+        let value_start = position + 3;
+        if value_start + len > data.len() {
+            return Err(VpdError::Truncated(start + position));
+        }
+
+        if keyword == KEYWORD_CHECKSUM && len > 0 {
+            checksum = Some(start + value_start);
+        }
+        keywords.insert(keyword, data[value_start..value_start + len].to_vec());
+        position = value_start + len;
+    }
+
+    Ok((keywords, checksum))
+}
+
+pub fn vpd_keyword_name(keyword: &str) -> &'static str {
+    let bytes = keyword.as_bytes();
+    match bytes {
+        [b'V', _] => "Vendor specific",
+        [b'Y', _] if keyword != "YA" => "System specific",
+        _ => KEYWORD_NAMES
+            .iter()
+            .find(|(key, _)| *key == keyword)
+            .map(|(_, name)| *name)
+            .unwrap_or("Unknown"),
+    }
+}
+
+pub struct VpdPrettyPrinter {}
+
+impl Default for VpdPrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VpdPrettyPrinter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    // text when printable, hex bytes otherwise
+    fn format_value(&self, value: &[u8]) -> String {
+        let is_text = value.iter().all(|byte| (0x20..0x7f).contains(byte));
+        match is_text {
+            true => String::from_utf8_lossy(value).into_owned(),
+            false => {
+                let bytes: Vec<String> = value.iter().map(|byte| format!("{:02x}", byte)).collect();
+                bytes.join(" ")
+            }
+        }
+    }
+
+    // RV and RW only have their special meaning in the read-only and the
+    // read/write section respectively
+    fn print_keywords(
+        &self,
+        keywords: &BTreeMap<String, Vec<u8>>,
+        read_only: bool,
+        checksum_valid: Option<bool>,
+        lines: &mut Vec<String>,
+    ) {
+        for (keyword, value) in keywords {
+            let value = match keyword.as_str() {
+                KEYWORD_CHECKSUM if read_only => {
+                    let checksum = match checksum_valid {
+                        Some(true) => "good",
+                        _ => "bad",
+                    };
+                    format!(
+                        "checksum {}, {} byte(s) reserved",
+                        checksum,
+                        value.len().saturating_sub(1)
+                    )
+                }
+                KEYWORD_READ_WRITE if !read_only => format!("{} byte(s) free", value.len()),
+                _ => self.format_value(value),
+            };
+            lines.push(format!(
+                "  [{}] {}: {}",
+                keyword,
+                vpd_keyword_name(keyword),
+                value
+            ));
+        }
+    }
+
+    pub fn print(&self, vpd: &VitalProductData) -> Vec<String> {
+        let mut lines = vec![];
+
+        if let Some(identifier) = &vpd.identifier {
+            lines.push(format!("Product Name: {}", identifier));
+        }
+
+        if !vpd.read_only.is_empty() {
+            lines.push(String::from("Read-only fields:"));
+            self.print_keywords(&vpd.read_only, true, vpd.checksum_valid, &mut lines);
+        }
+
+        if !vpd.read_write.is_empty() {
+            lines.push(String::from("Read/write fields:"));
+            self.print_keywords(&vpd.read_write, false, None, &mut lines);
+        }
+
+        lines
+    }
+
+    pub fn print_error(&self, error: &VpdError) -> String {
+        match error {
+            VpdError::Truncated(offset) => format!("VPD truncated at 0x{:x}", offset),
+            VpdError::MissingEnd => String::from("VPD has no end tag"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a large resource tag with a 16-bit length
+    fn tag(name: u8, data: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![TAG_LARGE | name];
+        bytes.extend_from_slice(&(data.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    // an identifier, a VPD-R section with a part number and an RV checksum
+    // that makes everything up to it sum to zero, and an end tag
+    fn vpd() -> Vec<u8> {
+        let mut bytes = tag(TAG_IDENTIFIER, b"Example NIC");
+        bytes.extend(tag(TAG_VPD_R, b"PN\x04AB12RV\x01\x00"));
+        let sum = bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+        let checksum = bytes.len() - 1;
+        bytes[checksum] = 0u8.wrapping_sub(sum);
+        bytes.extend(tag(TAG_VPD_W, b"RW\x02\x00\x00"));
+        bytes.push(TAG_END << 3);
+        bytes
+    }
+
+    #[test]
+    fn parses_the_sections() {
+        let vpd = VitalProductData::parse(&vpd()).unwrap();
+        assert_eq!(vpd.identifier.as_deref(), Some("Example NIC"));
+        assert_eq!(vpd.part_number().as_deref(), Some("AB12"));
+        assert_eq!(vpd.read_only.len(), 2);
+        assert_eq!(vpd.read_write.get("RW"), Some(&vec![0, 0]));
+        assert_eq!(vpd.checksum_valid, Some(true));
+    }
+
+    #[test]
+    fn ignores_what_follows_the_end_tag() {
+        let mut bytes = vpd();
+        bytes.extend_from_slice(&[0xff; 16]);
+        assert_eq!(
+            VitalProductData::parse(&bytes),
+            VitalProductData::parse(&vpd())
+        );
+    }
+
+    #[test]
+    fn detects_a_bad_checksum() {
+        let mut bytes = vpd();
+        // the last byte of the part number
+        bytes[0x17] ^= 0x01;
+        let vpd = VitalProductData::parse(&bytes).unwrap();
+        assert_eq!(vpd.checksum_valid, Some(false));
+    }
+
+    #[test]
+    fn fails_on_a_truncated_buffer() {
+        let bytes = vpd();
+        // inside the VPD-R tag, which starts after the 14 byte identifier
+        assert_eq!(
+            VitalProductData::parse(&bytes[..20]),
+            Err(VpdError::Truncated(0x0e))
+        );
+        // a keyword whose length runs past the end of its section
+        let bytes = tag(TAG_VPD_R, b"PN\x08AB12");
+        assert_eq!(VitalProductData::parse(&bytes), Err(VpdError::Truncated(3)));
+    }
+
+    #[test]
+    fn fails_without_an_end_tag() {
+        let bytes = vpd();
+        assert_eq!(
+            VitalProductData::parse(&bytes[..bytes.len() - 1]),
+            Err(VpdError::MissingEnd)
+        );
+    }
+
+    #[test]
+    fn only_checks_rv_in_the_read_only_section() {
+        let mut bytes = tag(TAG_VPD_W, b"RV\x01\x00");
+        bytes.push(TAG_END << 3);
+        let vpd = VitalProductData::parse(&bytes).unwrap();
+        assert_eq!(vpd.checksum_valid, None);
+
+        let lines = VpdPrettyPrinter::new().print(&vpd);
+        assert_eq!(lines, vec!["Read/write fields:", "  [RV] Reserved: 00"]);
+    }
+}
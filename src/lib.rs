@@ -1,12 +1,100 @@
 #![no_std]
 
 extern crate alloc;
-#[macro_use]
-extern crate lazy_static;
 
 mod address;
 mod config_space;
 
 pub use address::Address;
+pub use config_space::aspm_name;
+pub use config_space::capability_name;
+pub use config_space::class_name;
+pub use config_space::describe_class;
+pub use config_space::describe_subsystem;
+pub use config_space::extended_capability_name;
+pub use config_space::format_size;
+pub use config_space::id_database_version;
+pub use config_space::link_speed_name;
+pub use config_space::prog_if_name;
+pub use config_space::subclass_name;
+pub use config_space::subsystem_name;
+pub use config_space::vpd_keyword_name;
+pub use config_space::AccessControlServicesCapability;
+pub use config_space::AccessControlServicesPrettyPrinter;
+pub use config_space::AddressTranslationServicesCapability;
+pub use config_space::AddressTranslationServicesPrettyPrinter;
+pub use config_space::AdvancedErrorReportingCapability;
+pub use config_space::AdvancedErrorReportingPrettyPrinter;
+pub use config_space::BarDecoder;
+pub use config_space::BarKind;
+pub use config_space::BarPrettyPrinter;
+pub use config_space::BaseAddressRegister;
+pub use config_space::BridgeWindow;
+pub use config_space::BridgeWindows;
+pub use config_space::Capability;
+pub use config_space::CapabilityError;
+pub use config_space::CapabilityIter;
+pub use config_space::CapabilityPrettyPrinter;
 pub use config_space::ConfigSpace;
+pub use config_space::ConfigSpaceError;
 pub use config_space::ConfigSpacePrettyPrinter;
+pub use config_space::DownstreamPortContainmentCapability;
+pub use config_space::DownstreamPortContainmentPrettyPrinter;
+pub use config_space::EfiImageHeader;
+pub use config_space::ExpansionRom;
+pub use config_space::ExtendedCapability;
+pub use config_space::ExtendedCapabilityError;
+pub use config_space::ExtendedCapabilityIter;
+pub use config_space::ExtendedCapabilityPrettyPrinter;
+pub use config_space::IsolationPath;
+pub use config_space::IsolationPrettyPrinter;
+pub use config_space::IsolationReason;
+pub use config_space::IsolationReport;
+pub use config_space::L1PmSubstatesCapability;
+pub use config_space::L1PmSubstatesPrettyPrinter;
+pub use config_space::LatencyToleranceReportingCapability;
+pub use config_space::LatencyToleranceReportingPrettyPrinter;
+pub use config_space::LinkPowerPrettyPrinter;
+pub use config_space::LinkPowerReport;
+pub use config_space::LinkPowerStates;
+pub use config_space::MsiCapability;
+pub use config_space::MsiPrettyPrinter;
+pub use config_space::MsixCapability;
+pub use config_space::OptionRom;
+pub use config_space::OptionRomPrettyPrinter;
+pub use config_space::PageRequestInterfaceCapability;
+pub use config_space::PageRequestInterfacePrettyPrinter;
+pub use config_space::PasidCapability;
+pub use config_space::PasidPrettyPrinter;
+pub use config_space::PciExpressCapability;
+pub use config_space::PciExpressPrettyPrinter;
+pub use config_space::PciIds;
+pub use config_space::PortType;
+pub use config_space::PowerManagementCapability;
+pub use config_space::PowerManagementPrettyPrinter;
+pub use config_space::PowerState;
+pub use config_space::PrecisionTimeMeasurementCapability;
+pub use config_space::PrecisionTimeMeasurementPrettyPrinter;
+pub use config_space::ResizableBar;
+pub use config_space::ResizableBarCapability;
+pub use config_space::ResizableBarPrettyPrinter;
+pub use config_space::ResizableBarReport;
+pub use config_space::ResourceSizes;
+pub use config_space::RomCodeType;
+pub use config_space::RomError;
+pub use config_space::RomImage;
+pub use config_space::RpPioRegisters;
+pub use config_space::SriovCapability;
+pub use config_space::SriovPrettyPrinter;
+pub use config_space::VendorCapability;
+pub use config_space::VendorCapabilityDecoder;
+pub use config_space::VendorCapabilityKey;
+pub use config_space::VendorCapabilityPrettyPrinter;
+pub use config_space::VendorCapabilityRegistry;
+pub use config_space::VitalProductData;
+pub use config_space::VpdCapability;
+pub use config_space::VpdCapabilityPrettyPrinter;
+pub use config_space::VpdError;
+pub use config_space::VpdPrettyPrinter;
+pub use config_space::EXT_CAP_ID_ACS;
+pub use config_space::EXT_CAP_ID_RESIZABLE_BAR;
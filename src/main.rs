@@ -1,10 +1,41 @@
+use std::collections::BTreeMap;
+use std::env;
 use std::fs;
+use std::io;
 use std::path;
-use std::u8;
+use std::process;
 
 use pcitools::Address;
 use pcitools::ConfigSpace;
 use pcitools::ConfigSpacePrettyPrinter;
+use pcitools::IsolationPath;
+use pcitools::IsolationPrettyPrinter;
+use pcitools::IsolationReport;
+use pcitools::LinkPowerPrettyPrinter;
+use pcitools::LinkPowerReport;
+use pcitools::OptionRom;
+use pcitools::OptionRomPrettyPrinter;
+use pcitools::PciIds;
+use pcitools::ResizableBarCapability;
+use pcitools::ResizableBarPrettyPrinter;
+use pcitools::ResizableBarReport;
+use pcitools::ResourceSizes;
+use pcitools::VitalProductData;
+use pcitools::VpdPrettyPrinter;
+use pcitools::EXT_CAP_ID_RESIZABLE_BAR;
+
+const SYSFS_ROOT: &str = "/sys/bus/pci/devices";
+
+// the standard header, all that unprivileged reads of sysfs return
+const HEADER_LEN: usize = 0x40;
+
+// where distributions install pci.ids, in order of preference
+const PCI_IDS_PATHS: [&str; 2] = ["/usr/share/hwdata/pci.ids", "/usr/share/misc/pci.ids"];
+
+// local overrides in pci.ids syntax, from this variable or else from
+// pcitools/pci.ids under the user's config directory
+const LOCAL_IDS_VAR: &str = "PCITOOLS_LOCAL_IDS";
+const LOCAL_IDS_PATH: &str = "pcitools/pci.ids";
 
 struct Scanner {
     root_dir: String,
@@ -20,7 +51,7 @@ impl Scanner {
     fn scan(&self) -> Vec<Address> {
         let mut addresses = vec![];
 
-        // enumerate pci buses
+        // enumerate pci buses, named domain:bus outside of domain 0
         for bus_entry in fs::read_dir(&self.root_dir).unwrap() {
             let bus_entry = bus_entry.unwrap();
 
@@ -32,6 +63,11 @@ impl Scanner {
                 continue;
             }
 
+            let (domain_no, bus_id) = match bus_id.split_once(':') {
+                Some((domain_id, bus_id)) => (u32::from_str_radix(domain_id, 16).unwrap(), bus_id),
+                None => (0, bus_id),
+            };
+
             // enumerate pci devices for each bus
             for device_entry in fs::read_dir(&bus_path).unwrap() {
                 let device_entry = device_entry.unwrap();
@@ -43,7 +79,7 @@ impl Scanner {
                 let dev_no: u8 = u8::from_str_radix(parts[0], 16).unwrap();
                 let func_no: u8 = u8::from_str_radix(parts[1], 16).unwrap();
 
-                let address = Address::new(bus_no, dev_no, func_no);
+                let address = Address::with_domain(domain_no, bus_no, dev_no, func_no);
                 addresses.push(address);
             }
         }
@@ -52,37 +88,474 @@ impl Scanner {
         addresses
     }
 
-    fn load_space(&self, address: &Address) -> ConfigSpace {
-        let bus_name = format!("{:02x}", address.bus);
+    // fails for addresses that don't exist, and for files too short to hold
+    // the header
+    fn load_space(&self, address: &Address) -> io::Result<ConfigSpace> {
+        let bus_name = match address.domain {
+            0 => format!("{:02x}", address.bus),
+            domain => format!("{:04x}:{:02x}", domain, address.bus),
+        };
         let dev_func = format!("{:02x}.{:x}", address.device, address.function);
         let path = path::Path::new(&self.root_dir)
             .join(bus_name)
             .join(dev_func);
 
-        let bytes = fs::read(&path).unwrap();
-        ConfigSpace::from(bytes)
+        let bytes = fs::read(&path)?;
+        if bytes.len() < HEADER_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "config space shorter than the header",
+            ));
+        }
+        Ok(ConfigSpace::from(bytes))
+    }
+
+    fn sysfs_dir(&self, address: &Address) -> path::PathBuf {
+        let name = format!(
+            "{:04x}:{:02x}:{:02x}.{:x}",
+            address.domain, address.bus, address.device, address.function
+        );
+        path::Path::new(SYSFS_ROOT).join(name)
+    }
+
+    // physical function of a VF, or the virtual functions of a PF, from the
+    // physfn and virtfnN links in sysfs
+    fn load_sriov_links(&self, address: &Address) -> Vec<(String, String)> {
+        let dir = self.sysfs_dir(address);
+        let mut links = vec![];
+
+        if let Ok(target) = fs::read_link(dir.join("physfn")) {
+            links.push(("physfn".to_owned(), link_name(&target)));
+        }
+
+        let mut virtfns: Vec<(u32, String)> = match fs::read_dir(&dir) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    let name = entry.file_name().to_str()?.to_owned();
+                    let index = name.strip_prefix("virtfn")?.parse().ok()?;
+                    let target = fs::read_link(entry.path()).ok()?;
+                    Some((index, link_name(&target)))
+                })
+                .collect(),
+            Err(_) => vec![],
+        };
+        virtfns.sort();
+
+        for (index, target) in virtfns {
+            links.push((format!("virtfn{}", index), target));
+        }
+
+        links
+    }
+
+    fn load_iommu_group(&self, address: &Address) -> Option<u32> {
+        let target = fs::read_link(self.sysfs_dir(address).join("iommu_group")).ok()?;
+        link_name(&target).parse().ok()
+    }
+
+    // the bridge directly above a device, from its place in the sysfs tree
+    fn load_upstream_bridge(&self, address: &Address) -> Option<Address> {
+        let dir = fs::canonicalize(self.sysfs_dir(address)).ok()?;
+        let parent = dir.parent()?.file_name()?.to_str()?;
+        parse_sysfs_name(parent)
+    }
+
+    // only readable by root, and only present on devices with the capability
+    fn load_vpd(&self, address: &Address) -> Option<Vec<u8>> {
+        fs::read(self.sysfs_dir(address).join("vpd")).ok()
+    }
+
+    // only readable after enabling it with `echo 1 > rom`
+    fn load_rom(&self, address: &Address) -> Option<Vec<u8>> {
+        fs::read(self.sysfs_dir(address).join("rom")).ok()
+    }
+
+    // prefer sysfs, fall back to the device list in procfs
+    fn load_resources(&self, address: &Address) -> ResourceSizes {
+        let path = self.sysfs_dir(address).join("resource");
+        if let Ok(text) = fs::read_to_string(&path) {
+            return ResourceSizes::from_sysfs_resource(&text);
+        }
+
+        // the procfs list doesn't say which domain a device is in
+        if address.domain != 0 {
+            return ResourceSizes::new();
+        }
+
+        let path = path::Path::new(&self.root_dir).join("devices");
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(_) => return ResourceSizes::new(),
+        };
+
+        let bus_devfn = format!(
+            "{:02x}{:02x}",
+            address.bus,
+            address.device << 3 | address.function
+        );
+        text.lines()
+            .find(|line| line.starts_with(&bus_devfn))
+            .map(ResourceSizes::from_proc_devices_line)
+            .unwrap_or_default()
+    }
+}
+
+fn link_name(target: &path::Path) -> String {
+    match target.file_name() {
+        Some(name) => name.to_string_lossy().into_owned(),
+        None => target.to_string_lossy().into_owned(),
+    }
+}
+
+// sysfs names devices as domain:bus:device.function
+fn parse_sysfs_name(name: &str) -> Option<Address> {
+    let parts: Vec<&str> = name.split([':', '.']).collect();
+    if parts.len() != 4 {
+        return None;
+    }
+
+    let domain_no = u32::from_str_radix(parts[0], 16).ok()?;
+    let bus_no = u8::from_str_radix(parts[1], 16).ok()?;
+    let dev_no = u8::from_str_radix(parts[2], 16).ok()?;
+    let func_no = u8::from_str_radix(parts[3], 16).ok()?;
+    Some(Address::with_domain(domain_no, bus_no, dev_no, func_no))
+}
+
+// an address as given on the command line, with or without the domain.
+// Device numbers go up to 1f and function numbers up to 7.
+fn parse_address(name: &str) -> Option<Address> {
+    let address = match name.matches(':').count() {
+        1 => parse_sysfs_name(&format!("0000:{}", name))?,
+        _ => parse_sysfs_name(name)?,
+    };
+    match address.device <= 0x1f && address.function <= 0x7 {
+        true => Some(address),
+        false => None,
+    }
+}
+
+// a member of an IOMMU group with the config space of each bridge above it
+fn load_isolation_path(scanner: &Scanner, address: &Address) -> IsolationPath {
+    let mut bridges = vec![];
+    let mut current = *address;
+    while let Some(bridge) = scanner.load_upstream_bridge(&current) {
+        bridges.push((bridge, scanner.load_space(&bridge).ok()));
+        current = bridge;
+    }
+
+    IsolationPath {
+        address: *address,
+        config_space: scanner.load_space(address).ok(),
+        bridges,
+    }
+}
+
+// resizable BARs next to the assigned resources and the window of the bridge
+// above, which together limit how large the BAR can be made
+fn load_rebar_reports(
+    scanner: &Scanner,
+    address: &Address,
+    conf: &ConfigSpace,
+    sizes: &ResourceSizes,
+) -> Vec<ResizableBarReport> {
+    let rebar = match conf.find_extended_capability(EXT_CAP_ID_RESIZABLE_BAR) {
+        Some(cap) => ResizableBarCapability::read(conf, cap.offset),
+        None => None,
+    };
+    let rebar = match rebar {
+        Some(rebar) => rebar,
+        None => return vec![],
+    };
+
+    let upstream = scanner
+        .load_upstream_bridge(address)
+        .and_then(|bridge| scanner.load_space(&bridge).ok());
+    ResizableBarReport::analyze(conf, &rebar, sizes, upstream.as_ref())
+}
+
+fn print_rebar_report(scanner: &Scanner, addresses: &[Address]) {
+    let printer = ResizableBarPrettyPrinter::new();
+
+    println!("Resizable BAR");
+    let mut found = false;
+    for address in addresses {
+        // unreadable devices were already reported in the listing
+        let conf = match scanner.load_space(address) {
+            Ok(conf) => conf,
+            Err(_) => continue,
+        };
+        let sizes = scanner.load_resources(address);
+
+        for report in load_rebar_reports(scanner, address, &conf, &sizes) {
+            if !report.is_undersized_gpu() {
+                continue;
+            }
+            found = true;
+            if let Some(line) = printer.print_report(&report).last() {
+                println!("  {}: BAR{}: {}", address, report.bar.bar_index, line);
+            }
+        }
+    }
+
+    if !found {
+        println!("  no GPUs with a BAR smaller than supported");
+    }
+}
+
+// ASPM and L1 substates of each link, from the downstream facing port and
+// function 0 of the device below it, which controls the link for all functions
+fn print_link_power_report(scanner: &Scanner, addresses: &[Address]) {
+    let printer = LinkPowerPrettyPrinter::new();
+
+    println!("ASPM links");
+    let mut found = false;
+    for address in addresses.iter().filter(|address| address.function == 0) {
+        let bridge = match scanner.load_upstream_bridge(address) {
+            Some(bridge) => bridge,
+            None => continue,
+        };
+        let upstream = match scanner.load_space(&bridge) {
+            Ok(upstream) => upstream,
+            Err(_) => continue,
+        };
+        let is_port = matches!(
+            upstream.pci_express(),
+            Some(pcie) if pcie.port_type().is_downstream_facing()
+        );
+        if !is_port {
+            continue;
+        }
+
+        let downstream = match scanner.load_space(address) {
+            Ok(downstream) => downstream,
+            Err(_) => continue,
+        };
+        if let Some(report) = LinkPowerReport::analyze(&upstream, &downstream) {
+            found = true;
+            println!("  {} -> {}", bridge, address);
+            for line in printer.print(&report) {
+                println!("    {}", line);
+            }
+        }
+    }
+
+    if !found {
+        println!("  no PCI Express links found");
+    }
+}
+
+fn print_isolation_report(scanner: &Scanner, addresses: &[Address]) {
+    let printer = IsolationPrettyPrinter::new();
+
+    let mut groups: BTreeMap<u32, Vec<Address>> = BTreeMap::new();
+    for address in addresses {
+        if let Some(group) = scanner.load_iommu_group(address) {
+            groups.entry(group).or_default().push(*address);
+        }
+    }
+
+    println!("IOMMU groups");
+    if groups.is_empty() {
+        println!("  no IOMMU groups found, the IOMMU may be disabled");
+        return;
+    }
+
+    for (group, members) in &groups {
+        let names: Vec<String> = members.iter().map(|member| member.to_string()).collect();
+        println!("  group {}: {}", group, names.join(" "));
+
+        if members.len() < 2 {
+            continue;
+        }
+
+        let paths: Vec<IsolationPath> = members
+            .iter()
+            .map(|member| load_isolation_path(scanner, member))
+            .collect();
+        let report = IsolationReport::analyze(&paths);
+        for line in printer.print(&report) {
+            println!("    {}", line);
+        }
+    }
+}
+
+// `rom <file> [[domain:]bus:device.function]` parses an option ROM saved to disk,
+// checking it against the device if one is given
+fn print_rom_file(scanner: &Scanner, path: &str, address: Option<&String>) {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            eprintln!("{}: {}", path, error);
+            return;
+        }
+    };
+
+    let conf = match address {
+        Some(name) => match parse_address(name) {
+            Some(address) => match scanner.load_space(&address) {
+                Ok(conf) => Some(conf),
+                Err(error) => {
+                    eprintln!("{}: {}", name, error);
+                    return;
+                }
+            },
+            None => {
+                eprintln!("{}: not a device address", name);
+                return;
+            }
+        },
+        None => None,
+    };
+
+    let rom = OptionRom::parse(&bytes);
+    for line in OptionRomPrettyPrinter::new().print(&rom, conf.as_ref()) {
+        println!("{}", line);
+    }
+}
+
+// names from `path` if given, otherwise from the first system pci.ids found.
+// Without one only the embedded names are used. A path that was asked for
+// has to be readable.
+fn load_ids(path: Option<&String>) -> io::Result<PciIds> {
+    if let Some(path) = path {
+        return fs::read_to_string(path).map(|text| PciIds::parse(&text));
+    }
+
+    Ok(PCI_IDS_PATHS
+        .iter()
+        .find_map(|path| fs::read_to_string(path).ok())
+        .map(|text| PciIds::parse(&text))
+        .unwrap_or_default())
+}
+
+// takes `--ids <path>` out of the arguments, wherever it is
+fn take_ids_path(args: &mut Vec<String>) -> Result<Option<String>, String> {
+    let index = match args.iter().position(|arg| arg == "--ids") {
+        Some(index) => index,
+        None => return Ok(None),
+    };
+    if index + 1 >= args.len() {
+        return Err(String::from("--ids needs the path of a pci.ids file"));
+    }
+
+    let path = args.remove(index + 1);
+    args.remove(index);
+    Ok(Some(path))
+}
+
+fn local_ids_path() -> Option<path::PathBuf> {
+    if let Some(path) = env::var_os(LOCAL_IDS_VAR) {
+        return Some(path::PathBuf::from(path));
+    }
+
+    let config_dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => path::PathBuf::from(dir),
+        None => path::Path::new(&env::var_os("HOME")?).join(".config"),
+    };
+    Some(config_dir.join(LOCAL_IDS_PATH))
+}
+
+// a missing overrides file is fine, but one named explicitly should exist
+fn load_local_ids(ids: &mut PciIds) {
+    let path = match local_ids_path() {
+        Some(path) => path,
+        None => return,
+    };
+
+    match fs::read_to_string(&path) {
+        Ok(text) => ids.set_local(&text),
+        Err(error) if env::var_os(LOCAL_IDS_VAR).is_some() => {
+            eprintln!("{}: {}", path.display(), error);
+        }
+        Err(_) => {}
     }
 }
 
 fn main() {
     let root_dir = "/proc/bus/pci";
     let scanner = Scanner::new(root_dir);
+
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let ids_path = match take_ids_path(&mut args) {
+        Ok(path) => path,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(2);
+        }
+    };
+    if args.len() >= 2 && args[0] == "rom" {
+        print_rom_file(&scanner, &args[1], args.get(2));
+        return;
+    }
+
     let addresses = scanner.scan();
 
-    let printer = ConfigSpacePrettyPrinter::new();
+    let mut printer = ConfigSpacePrettyPrinter::new();
+    let mut ids = match load_ids(ids_path.as_ref()) {
+        Ok(ids) => ids,
+        Err(error) => {
+            // ids_path is set, the system files can't fail
+            eprintln!("{}: {}", ids_path.unwrap_or_default(), error);
+            process::exit(1);
+        }
+    };
+    load_local_ids(&mut ids);
+    printer.set_ids(ids);
+    let rebar_printer = ResizableBarPrettyPrinter::new();
+    let vpd_printer = VpdPrettyPrinter::new();
+    let rom_printer = OptionRomPrettyPrinter::new();
 
-    for address in addresses {
-        println!(
-            "{:02x}:{:02x}.{:x} ",
-            address.bus, address.device, address.function
-        );
+    for address in &addresses {
+        let conf = match scanner.load_space(address) {
+            Ok(conf) => conf,
+            Err(error) => {
+                eprintln!("{}: {}", address, error);
+                continue;
+            }
+        };
+        println!("{} {}", address, printer.print_class(&conf));
+        if let Some(subsystem) = printer.print_subsystem(&conf) {
+            println!("  Subsystem: {}", subsystem);
+        }
 
-        let conf = scanner.load_space(&address);
+        let sizes = scanner.load_resources(address);
+
+        let mut block = printer.print_with_resources(&conf, &sizes);
+        for (name, target) in scanner.load_sriov_links(address) {
+            block.push_str(&format!("{:<20}: {}\n", name, target));
+        }
+        for report in load_rebar_reports(&scanner, address, &conf, &sizes) {
+            for line in rebar_printer.print_report(&report) {
+                block.push_str(&format!("{:<20}: {}\n", "rebar", line));
+            }
+        }
+        if let Some(bytes) = scanner.load_vpd(address) {
+            let lines = match VitalProductData::parse(&bytes) {
+                Ok(vpd) => vpd_printer.print(&vpd),
+                Err(error) => vec![vpd_printer.print_error(&error)],
+            };
+            for line in lines {
+                block.push_str(&format!("{:<20}: {}\n", "vpd", line));
+            }
+        }
+        if let Some(bytes) = scanner.load_rom(address) {
+            let rom = OptionRom::parse(&bytes);
+            for line in rom_printer.print(&rom, Some(&conf)) {
+                block.push_str(&format!("{:<20}: {}\n", "rom", line));
+            }
+        }
+        if let Some(group) = scanner.load_iommu_group(address) {
+            block.push_str(&format!("{:<20}: {}\n", "iommu_group", group));
+        }
 
-        let block = printer.print(&conf);
-        let lines = block.split("\n");
+        let lines = block.split('\n');
         for line in lines {
             println!("  {}", line);
         }
     }
+
+    print_rebar_report(&scanner, &addresses);
+    print_link_power_report(&scanner, &addresses);
+    print_isolation_report(&scanner, &addresses);
 }